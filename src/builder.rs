@@ -0,0 +1,566 @@
+use regex::{bytes, Regex, RegexBuilder};
+
+use crate::cache::{self, CacheKey};
+use crate::captures::KoreanRegex;
+use crate::parser::is_set_name_char;
+use crate::substitute::sanitize_named_set;
+use crate::{compile_pieces, KoreanRegexError, Order, SlotKind};
+
+/// 한국어 문법을 정규표현식으로 바꿀 때 사용하는 옵션입니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct CompileOptions {
+    pub(crate) order: Order,
+    pub(crate) use_hyphen: bool,
+    pub(crate) strict: bool,
+    pub(crate) conjoining: bool,
+    pub(crate) compatibility_forms: bool,
+    pub(crate) partial: bool,
+    pub(crate) named_sets: Vec<NamedSet>,
+    /// 슬롯마다 match된 자모를 알 수 있도록 각 슬롯을 이름이 붙은 캡처 그룹으로 감쌀지 여부입니다.
+    pub(crate) captures: bool,
+}
+
+impl CompileOptions {
+    pub(crate) fn new(order: Order) -> Self {
+        CompileOptions {
+            order,
+            use_hyphen: true,
+            strict: false,
+            conjoining: false,
+            compatibility_forms: false,
+            partial: false,
+            named_sets: Vec::new(),
+            captures: false,
+        }
+    }
+}
+
+/// 빌더에 등록된, 슬롯의 한 자리에서 `@이름`으로 쓸 수 있는 자모 집합입니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct NamedSet {
+    pub(crate) name: String,
+    pub(crate) kind: SlotKind,
+    /// 순서대로 정렬되어 있고 중복이 없는 자모들입니다.
+    pub(crate) jamos: Vec<char>,
+}
+
+/// `regex::RegexBuilder`에 넘길 옵션입니다. 정하지 않은 옵션은 `regex`의 기본값을 따릅니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub(crate) struct RegexOptions {
+    case_insensitive: Option<bool>,
+    multi_line: Option<bool>,
+    dot_matches_new_line: Option<bool>,
+    crlf: Option<bool>,
+    line_terminator: Option<u8>,
+    swap_greed: Option<bool>,
+    ignore_whitespace: Option<bool>,
+    unicode: Option<bool>,
+    octal: Option<bool>,
+    size_limit: Option<usize>,
+    dfa_size_limit: Option<usize>,
+    nest_limit: Option<u32>,
+}
+
+/// `regex::RegexBuilder`와 `regex::bytes::RegexBuilder`는 같은 이름의 메서드를 가지지만
+/// 공통된 trait이 없기에 매크로로 옵션을 적용합니다.
+macro_rules! apply_regex_options {
+    ($options:expr, $builder:expr, [$($option:ident),*]) => {
+        $(
+            if let Some(value) = $options.$option {
+                $builder.$option(value);
+            }
+        )*
+    };
+}
+
+impl RegexOptions {
+    fn apply(&self, builder: &mut RegexBuilder) {
+        apply_regex_options!(self, builder, [
+            case_insensitive, multi_line, dot_matches_new_line, crlf, line_terminator, swap_greed,
+            ignore_whitespace, unicode, octal, size_limit, dfa_size_limit, nest_limit
+        ]);
+    }
+
+    fn apply_bytes(&self, builder: &mut bytes::RegexBuilder) {
+        apply_regex_options!(self, builder, [
+            case_insensitive, multi_line, dot_matches_new_line, crlf, line_terminator, swap_greed,
+            ignore_whitespace, unicode, octal, size_limit, dfa_size_limit, nest_limit
+        ]);
+    }
+}
+
+/// 한국어 regex 패턴을 여러 옵션과 함께 컴파일하는 빌더입니다.
+///
+/// `regex::RegexBuilder`의 모든 옵션과 함께 순서(`Order`), 하이픈 압축, 엄격 모드와 같은
+/// 한국어 문법의 옵션을 정할 수 있습니다. 정하지 않은 옵션은 `compile`과 같습니다.
+///
+/// ```rust
+/// use korean_regex::*;
+///
+/// let regex = KoreanRegexBuilder::new("[ㄱ:ㅏ:ㄱ]abc")
+///     .case_insensitive(true)
+///     .build()
+///     .unwrap();
+/// assert!(regex.is_match("각ABC"));
+///
+/// let regex = KoreanRegexBuilder::new("[ㄱ:ㅏ:ㄱ-ㄷ]")
+///     .use_hyphen(false)
+///     .build()
+///     .unwrap();
+/// assert_eq!("[각갂갃간갅갆갇]", regex.as_str());
+/// ```
+///
+/// 컴파일된 결과는 `compile`과 같은 캐시를 사용하며, 캐시의 키에는 모든 옵션이 포함됩니다.
+#[derive(Debug, Clone)]
+pub struct KoreanRegexBuilder {
+    pattern: String,
+    options: CompileOptions,
+    regex_options: RegexOptions,
+}
+
+impl KoreanRegexBuilder {
+    /// 패턴으로 빌더를 만듭니다.
+    pub fn new(pattern: &str) -> Self {
+        KoreanRegexBuilder {
+            pattern: pattern.to_string(),
+            options: CompileOptions::new(Order::Default),
+            regex_options: RegexOptions::default(),
+        }
+    }
+
+    /// 하이픈 구성 시 사용할 순서를 정합니다. 기본값은 `Order::Default`입니다.
+    pub fn order(&mut self, order: Order) -> &mut Self {
+        self.options.order = order;
+        self
+    }
+
+    /// 연속된 문자를 `-`로 압축할지 정합니다. 기본값은 `true`입니다.
+    ///
+    /// 자세한 내용은 `substitute`의 use_hyphen을 참고하세요.
+    pub fn use_hyphen(&mut self, yes: bool) -> &mut Self {
+        self.options.use_hyphen = yes;
+        self
+    }
+
+    /// 엄격 모드를 사용할지 정합니다. 기본값은 `false`입니다.
+    ///
+    /// 자세한 내용은 `compilestr_strict`를 참고하세요.
+    pub fn strict(&mut self, yes: bool) -> &mut Self {
+        self.options.strict = yes;
+        self
+    }
+
+    /// 슬롯에 match되는 음절을 첫가끝 자모(U+1100–U+11FF)로 적은 경우에도 match할지 정합니다.
+    /// 기본값은 `false`입니다.
+    ///
+    /// NFD로 정규화된 문자열에서는 `간`이 `ᄀ`, `ᅡ`, `ᆫ` 세 문자로 적힙니다.
+    /// 이 옵션을 켜면 각 슬롯은 완성형 음절과 첫가끝 자모의 나열 모두에 match합니다.
+    /// 초성이나 중성만 있는 슬롯(`[ㄱ:0:0]` 등)과 `|` 뒤의 문자들은 바뀌지 않습니다.
+    ///
+    /// 정규표현식 크레이트가 전방 탐색을 지원하지 않기에 종성이 없는 슬롯(`[ㄱ:ㅏ]` 등)은
+    /// 첫가끝 자모로 적힌 `각`의 앞부분(`가`)에도 match한다는 점을 주의해 주세요.
+    ///
+    /// ```rust
+    /// use korean_regex::*;
+    ///
+    /// let regex = KoreanRegexBuilder::new("[ㄱ:ㅏ:ㄴ]")
+    ///     .conjoining(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!("(?:[간]|[ᄀ][ᅡ][ᆫ])", regex.as_str());
+    /// assert!(regex.is_match("간"));
+    /// assert!(regex.is_match("\u{1100}\u{1161}\u{11AB}"));
+    /// ```
+    pub fn conjoining(&mut self, yes: bool) -> &mut Self {
+        self.options.conjoining = yes;
+        self
+    }
+
+    /// 슬롯에 match되는 자모나 음절의 다른 형태에도 match할지 정합니다. 기본값은 `false`입니다.
+    ///
+    /// 반각 자모(`ﾡ` 등), 괄호 친 자모와 음절(`㈀`, `㈎` 등), 원문자 자모와 음절(`㉠`, `㉮` 등)이
+    /// 각각 대응하는 호환용 자모나 완성형 음절과 같은 것으로 취급됩니다.
+    /// 문자열 쪽을 정규화하려면 [`hangul::normalize_form`](crate::hangul::normalize_form)을 사용하세요.
+    ///
+    /// ```rust
+    /// use korean_regex::*;
+    ///
+    /// let regex = KoreanRegexBuilder::new("[ㄱ:0:0][ㄱ:ㅏ]")
+    ///     .compatibility_forms(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(regex.is_match("ㄱ가"));
+    /// assert!(regex.is_match("\u{FFA1}㉮"));
+    /// assert!(regex.is_match("㈀㈎"));
+    /// ```
+    pub fn compatibility_forms(&mut self, yes: bool) -> &mut Self {
+        self.options.compatibility_forms = yes;
+        self
+    }
+
+    /// 입력 중인 음절에도 match할지 정합니다. 기본값은 `false`입니다.
+    ///
+    /// 입력기로 글자를 입력하는 도중에는 `닭`이 `ㄷ`, `다`, `달`을 거쳐 완성됩니다.
+    /// 이 옵션을 켜면 각 슬롯은 슬롯에 match되는 음절뿐 아니라 그 음절을 입력하는 도중에 나타나는
+    /// 초성 하나, 종성이 없는 음절, 이중 모음이나 겹받침의 앞부분만 입력된 음절에도 match합니다.
+    /// 검색창에서 사용자가 입력하는 도중에도 결과를 보여줄 때 유용합니다.
+    ///
+    /// ```rust
+    /// use korean_regex::*;
+    ///
+    /// let regex = KoreanRegexBuilder::new("^[ㄷ:ㅏ:ㄺ]$")
+    ///     .partial(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(regex.is_match("닭"));
+    /// assert!(regex.is_match("달"));
+    /// assert!(regex.is_match("다"));
+    /// assert!(regex.is_match("ㄷ"));
+    /// assert!(!regex.is_match("닥"));
+    /// ```
+    pub fn partial(&mut self, yes: bool) -> &mut Self {
+        self.options.partial = yes;
+        self
+    }
+
+    /// 슬롯의 `kind` 자리에서 `@name`으로 쓸 수 있는 자모 집합을 등록합니다.
+    ///
+    /// `jamos`는 슬롯의 한 자리와 같은 문법으로 적으며 `-` 범위와 괄호 문법을 쓸 수 있습니다.
+    /// 등록할 때 빌더의 순서(`Order`)에서 `kind` 자리에 올 수 있는 자모인지를 검사하기에,
+    /// 순서를 바꾸려면 집합을 등록하기 전에 바꾸어야 합니다.
+    /// 오류의 위치는 `name`이 잘못되었다면 `name`을, 그렇지 않다면 `jamos`를 기준으로 합니다.
+    ///
+    /// 같은 자리에 같은 이름으로 다시 등록하면 이전 집합을 대체하며, 기본으로 있는 집합(`@경음` 등)보다 우선합니다.
+    ///
+    /// ```rust
+    /// use korean_regex::*;
+    ///
+    /// let regex = KoreanRegexBuilder::new("[ㄱ:ㅏ:@공명음]")
+    ///     .named_set("공명음", SlotKind::Jongsung, "ㄴㄹㅁㅇ")
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!("[간갈감강]", regex.as_str());
+    ///
+    /// let error = KoreanRegexBuilder::new("").named_set("공명음", SlotKind::Jongsung, "ㄴㄸ").unwrap_err();
+    /// assert_eq!(Some('ㄸ'), error.phoneme());
+    /// assert_eq!(3..6, error.span());
+    /// ```
+    pub fn named_set(&mut self, name: &str, kind: SlotKind, jamos: &str) -> Result<&mut Self, KoreanRegexError> {
+        if name.is_empty() || !name.chars().all(is_set_name_char) {
+            return Err(KoreanRegexError::InvalidSetError(
+                format!("`{name}` cannot be used as a name of named set."),
+                Some(kind),
+                0..name.len(),
+            ));
+        }
+        let jamos = sanitize_named_set(jamos, self.options.order, kind)?;
+        let named_set = NamedSet { name: name.to_string(), kind, jamos };
+        match self.options.named_sets.iter_mut().find(|set| set.name == name && set.kind == kind) {
+            Some(set) => *set = named_set,
+            None => self.options.named_sets.push(named_set),
+        }
+        Ok(self)
+    }
+
+    /// `regex::RegexBuilder::case_insensitive`와 같습니다.
+    pub fn case_insensitive(&mut self, yes: bool) -> &mut Self {
+        self.regex_options.case_insensitive = Some(yes);
+        self
+    }
+
+    /// `regex::RegexBuilder::multi_line`과 같습니다.
+    pub fn multi_line(&mut self, yes: bool) -> &mut Self {
+        self.regex_options.multi_line = Some(yes);
+        self
+    }
+
+    /// `regex::RegexBuilder::dot_matches_new_line`과 같습니다.
+    pub fn dot_matches_new_line(&mut self, yes: bool) -> &mut Self {
+        self.regex_options.dot_matches_new_line = Some(yes);
+        self
+    }
+
+    /// `regex::RegexBuilder::crlf`와 같습니다.
+    pub fn crlf(&mut self, yes: bool) -> &mut Self {
+        self.regex_options.crlf = Some(yes);
+        self
+    }
+
+    /// `regex::RegexBuilder::line_terminator`와 같습니다.
+    pub fn line_terminator(&mut self, byte: u8) -> &mut Self {
+        self.regex_options.line_terminator = Some(byte);
+        self
+    }
+
+    /// `regex::RegexBuilder::swap_greed`와 같습니다.
+    pub fn swap_greed(&mut self, yes: bool) -> &mut Self {
+        self.regex_options.swap_greed = Some(yes);
+        self
+    }
+
+    /// `regex::RegexBuilder::ignore_whitespace`와 같습니다.
+    pub fn ignore_whitespace(&mut self, yes: bool) -> &mut Self {
+        self.regex_options.ignore_whitespace = Some(yes);
+        self
+    }
+
+    /// `regex::RegexBuilder::unicode`와 같습니다.
+    pub fn unicode(&mut self, yes: bool) -> &mut Self {
+        self.regex_options.unicode = Some(yes);
+        self
+    }
+
+    /// `regex::RegexBuilder::octal`과 같습니다.
+    pub fn octal(&mut self, yes: bool) -> &mut Self {
+        self.regex_options.octal = Some(yes);
+        self
+    }
+
+    /// `regex::RegexBuilder::size_limit`과 같습니다.
+    pub fn size_limit(&mut self, bytes: usize) -> &mut Self {
+        self.regex_options.size_limit = Some(bytes);
+        self
+    }
+
+    /// `regex::RegexBuilder::dfa_size_limit`과 같습니다.
+    pub fn dfa_size_limit(&mut self, bytes: usize) -> &mut Self {
+        self.regex_options.dfa_size_limit = Some(bytes);
+        self
+    }
+
+    /// `regex::RegexBuilder::nest_limit`과 같습니다.
+    pub fn nest_limit(&mut self, limit: u32) -> &mut Self {
+        self.regex_options.nest_limit = Some(limit);
+        self
+    }
+
+    /// 한국어 문법을 바꾼 정규표현식 문자열을 반환합니다. `regex`의 옵션은 사용되지 않습니다.
+    pub fn build_str(&self) -> Result<String, KoreanRegexError> {
+        self.build_str_all_errors().map_err(|mut errors| errors.remove(0))
+    }
+
+    /// `build_str`과 같지만 패턴 안의 모든 오류를 모아 반환합니다.
+    pub fn build_str_all_errors(&self) -> Result<String, Vec<KoreanRegexError>> {
+        compile_pieces(&self.pattern, &self.options)
+    }
+
+    /// 패턴을 Regex로 컴파일합니다.
+    pub fn build(&self) -> Result<Regex, KoreanRegexError> {
+        let key = CacheKey {
+            pattern: self.pattern.clone(),
+            options: self.options.clone(),
+            regex_options: self.regex_options.clone(),
+        };
+        cache::get_or_compile(key, |key| {
+            let compiled = compile_pieces(&key.pattern, &key.options).map_err(|mut errors| errors.remove(0))?;
+            let mut builder = RegexBuilder::new(&compiled);
+            key.regex_options.apply(&mut builder);
+            builder
+                .build()
+                .map_err(|error| KoreanRegexError::RegexError(error, 0..key.pattern.len()))
+        })
+    }
+
+    /// 패턴을 슬롯마다 match된 음절과 자모를 알려주는 [`KoreanRegex`]로 컴파일합니다.
+    ///
+    /// 슬롯의 자리에 `(?<이름>...)`으로 이름을 붙이면 그 자리에 match된 자모를 이름으로 찾을 수 있습니다.
+    ///
+    /// ```rust
+    /// use korean_regex::*;
+    ///
+    /// let regex = KoreanRegexBuilder::new("[(?<c>ㄱㄹ):ㅏㅣ:ㄴㄹ]").build_captures().unwrap();
+    /// let captures = regex.captures("기린").unwrap();
+    /// assert_eq!(Some('ㄹ'), captures.name("c"));
+    /// assert_eq!(Some('ㅣ'), captures.slots()[0].jamo(SlotKind::Jungsung));
+    /// ```
+    pub fn build_captures(&self) -> Result<KoreanRegex, KoreanRegexError> {
+        let mut builder = self.clone();
+        builder.options.captures = true;
+        Ok(KoreanRegex::new(builder.build()?, &self.pattern, &self.options))
+    }
+
+    /// 패턴을 바이트 열에서 사용할 수 있는 `regex::bytes::Regex`로 컴파일합니다.
+    ///
+    /// 한국어 문법으로 만든 문자 클래스는 UTF-8로 인코딩된 음절에 match하므로
+    /// 올바르지 않은 UTF-8이 섞인 입력에서도 사용할 수 있습니다.
+    ///
+    /// ```rust
+    /// use korean_regex::*;
+    ///
+    /// let regex = KoreanRegexBuilder::new("[ㄱ:ㅏ:]+").build_bytes().unwrap();
+    /// let haystack = b"\xFFabc\xEA\xB0\x81\xEA\xB0\x84\xFE";
+    /// assert_eq!("각간".as_bytes(), regex.find(haystack).unwrap().as_bytes());
+    /// ```
+    pub fn build_bytes(&self) -> Result<bytes::Regex, KoreanRegexError> {
+        let key = CacheKey {
+            pattern: self.pattern.clone(),
+            options: self.options.clone(),
+            regex_options: self.regex_options.clone(),
+        };
+        cache::get_or_compile(key, |key| {
+            let compiled = compile_pieces(&key.pattern, &key.options).map_err(|mut errors| errors.remove(0))?;
+            let mut builder = bytes::RegexBuilder::new(&compiled);
+            key.regex_options.apply_bytes(&mut builder);
+            builder
+                .build()
+                .map_err(|error| KoreanRegexError::RegexError(error, 0..key.pattern.len()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{substitute, ErrorKind};
+
+    #[test]
+    fn test_builder() {
+        let regex = KoreanRegexBuilder::new("^[ㄱ:ㅏ:]$")
+            .multi_line(true)
+            .build()
+            .unwrap();
+        assert_eq!(2, regex.find_iter("각\n간").count());
+
+        let regex = KoreanRegexBuilder::new("[ㄱ-ㄹ:0:0]")
+            .order(Order::RegularFirst)
+            .build()
+            .unwrap();
+        assert_eq!("[ㄱㄴㄷㄹ]", regex.as_str());
+
+        match KoreanRegexBuilder::new("[ㅏ::]").strict(true).build().unwrap_err() {
+            KoreanRegexError::InvalidPhonemeError(_, chr, ..) => assert_eq!('ㅏ', chr),
+            _ => panic!("Should raise InvalidPhonemeError"),
+        }
+        assert_eq!("[]", KoreanRegexBuilder::new("[ㅏ::]").build_str().unwrap());
+
+        let pattern = "[::][::][::][::]";
+        match KoreanRegexBuilder::new(pattern).size_limit(10).build().unwrap_err() {
+            KoreanRegexError::RegexError(_, span) => assert_eq!(0..pattern.len(), span),
+            _ => panic!("Should raise RegexError"),
+        }
+    }
+
+    #[test]
+    fn test_builder_options_are_cached_separately() {
+        let pattern = "[ㄱ:ㅏ:ㄱ]a";
+        assert!(!KoreanRegexBuilder::new(pattern).build().unwrap().is_match("각A"));
+        assert!(KoreanRegexBuilder::new(pattern).case_insensitive(true).build().unwrap().is_match("각A"));
+        assert!(!KoreanRegexBuilder::new(pattern).build().unwrap().is_match("각A"));
+    }
+
+    #[test]
+    fn test_conjoining() {
+        let build = |pattern: &str| {
+            KoreanRegexBuilder::new(pattern).conjoining(true).build_str().unwrap()
+        };
+        assert_eq!("(?:[가-깋]|[ᄀ][ᅡ-ᅵ][ᆨ-ᇂ]?)", build("[ㄱ::]"));
+        assert_eq!("(?:[가간]|[ᄀ][ᅡ][ᆫ]?)", build("[ㄱ:ㅏ:0ㄴ]"));
+        assert_eq!("(?:[가나a]|[ᄀᄂ][ᅡ])", build("[ㄱㄴ:ㅏ|a]"));
+        assert_eq!("[ㄱㄲ]", build("[ㄱㄲ:0:0]"));
+
+        let regex = KoreanRegexBuilder::new("^[ㅎ:ㅏ:ㄴ][ㄱ:ㅡ:ㄹ]$").conjoining(true).build().unwrap();
+        assert!(regex.is_match("한글"));
+        assert!(regex.is_match("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}"));
+        assert!(regex.is_match("한\u{1100}\u{1173}\u{11AF}"));
+        assert!(!regex.is_match("\u{1112}\u{1161}\u{1100}\u{1173}\u{11AF}"));
+    }
+
+    #[test]
+    fn test_compatibility_forms() {
+        let build = |pattern: &str| {
+            KoreanRegexBuilder::new(pattern).compatibility_forms(true).build_str().unwrap()
+        };
+        assert_eq!("[ㄱㄲ\u{FFA1}㈀㉠\u{FFA2}]", build("[ㄱ-ㄲ:0:0]"));
+        assert_eq!("[ㅏ\u{FFC2}]", build("[0:ㅏ:0]"));
+        assert_eq!("[가-깋㈎㉮]", build("[ㄱ::]"));
+        assert_eq!("[각]", build("[ㄱ:ㅏ:ㄱ]"));
+        assert_eq!("[우㉾]", build("[ㅇ:ㅜ:0]"));
+        assert_eq!("[주㈜]", build("[ㅈ:ㅜ]"));
+    }
+
+    #[test]
+    fn test_partial() {
+        let build = |pattern: &str| {
+            KoreanRegexBuilder::new(pattern).partial(true).build_str().unwrap()
+        };
+        assert_eq!("[가-갛ㄱ]", build("[ㄱ:ㅏ:]"));
+        assert_eq!("[가나ㄱㄴ]", build("[ㄱㄴ:ㅏ]"));
+        assert_eq!("[고과곽ㄱ]", build("[ㄱ:ㅘ:0ㄱ]"));
+        assert_eq!("[다달닭ㄷ]", build("[ㄷ:ㅏ:ㄺ]"));
+        // 된소리는 나누지 않음
+        assert_eq!("[까깎ㄲ]", build("[ㄲ:ㅏ:ㄲ]"));
+        assert_eq!("[ㄱㄴ]", build("[ㄱㄴ:0:0]"));
+        assert_eq!(
+            "[가각ㄱa]",
+            KoreanRegexBuilder::new("[ㄱ:ㅏ:ㄱ|a]").partial(true).use_hyphen(false).build_str().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_named_set() {
+        let mut builder = KoreanRegexBuilder::new("[@파열음:ㅏ:@파열음]");
+        builder
+            .named_set("파열음", SlotKind::Chosung, "ㄱ-ㄸ(ㅂㅂ)ㅂ")
+            .unwrap()
+            .named_set("파열음", SlotKind::Jongsung, "ㄱㄷㅂ")
+            .unwrap();
+        let expected = substitute("ㄱㄲㄴㄷㄸㅂㅃ", "ㅏ", "ㄱㄷㅂ", Order::Default, true).unwrap();
+        assert_eq!(format!("[{expected}]"), builder.build_str().unwrap());
+
+        // 다시 등록하면 대체됨
+        builder.named_set("파열음", SlotKind::Chosung, "ㄱ").unwrap();
+        assert_eq!("[각갇갑]", builder.build_str().unwrap());
+        // 기본 집합보다 우선함
+        builder.pattern = "[@경음:ㅏ]".to_string();
+        builder.named_set("경음", SlotKind::Chosung, "ㄲ").unwrap();
+        assert_eq!("[까]", builder.build_str().unwrap());
+
+        // 등록된 집합만 있는 자리도 슬롯이 됨
+        let mut builder = KoreanRegexBuilder::new("[::@공명음]");
+        builder.named_set("공명음", SlotKind::Jongsung, "ㄴㄹㅁㅇ").unwrap();
+        assert_eq!(KoreanRegexBuilder::new("[::ㄴㄹㅁㅇ]").build_str().unwrap(), builder.build_str().unwrap());
+        let regex = builder.build_captures().unwrap();
+        assert_eq!(Some('ㅁ'), regex.captures("감").unwrap().slots()[0].jamo(SlotKind::Jongsung));
+        assert!(regex.captures("각").is_none());
+
+        // 등록되지 않은 자리에서는 쓸 수 없음
+        let error = KoreanRegexBuilder::new("[:@모음:]")
+            .named_set("모음", SlotKind::Chosung, "ㅇ")
+            .unwrap()
+            .build_str()
+            .unwrap_err();
+        assert_eq!(ErrorKind::InvalidSet, error.kind());
+        assert_eq!(Some(SlotKind::Jungsung), error.slot());
+
+        // 등록할 때 순서에 따라 검사함
+        let mut builder = KoreanRegexBuilder::new("");
+        let error = builder.named_set("옛", SlotKind::Chosung, "ㅿ").unwrap_err();
+        assert_eq!(ErrorKind::InvalidPhoneme, error.kind());
+        assert!(builder.order(Order::OldHangul).named_set("옛", SlotKind::Chosung, "ㅿ").is_ok());
+
+        let error = builder.named_set("a b", SlotKind::Chosung, "ㄱ").unwrap_err();
+        assert_eq!(ErrorKind::InvalidSet, error.kind());
+        let error = builder.named_set("빈", SlotKind::Chosung, "").unwrap_err();
+        assert_eq!(ErrorKind::InvalidSet, error.kind());
+        let error = builder.named_set("종성", SlotKind::Jongsung, "0").unwrap_err();
+        assert_eq!(ErrorKind::InvalidPhoneme, error.kind());
+    }
+
+    #[test]
+    fn test_build_bytes() {
+        let regex = KoreanRegexBuilder::new("(?i)[ㄱ:ㅏ:ㄱ]a").build_bytes().unwrap();
+        assert!(regex.is_match(b"\xFF\xEA\xB0\x81A"));
+        assert!(!regex.is_match("간a".as_bytes()));
+
+        // 같은 패턴이라도 Regex와 bytes::Regex는 따로 캐시됨
+        let pattern = "[ㄴ:ㅏ:ㄴ]";
+        assert_eq!("[난]", KoreanRegexBuilder::new(pattern).build().unwrap().as_str());
+        assert_eq!("[난]", KoreanRegexBuilder::new(pattern).build_bytes().unwrap().as_str());
+
+        match KoreanRegexBuilder::new("[ㄴ:ㅏ:ㄴ]").unicode(false).build_bytes().unwrap_err() {
+            KoreanRegexError::RegexError(..) => (),
+            _ => panic!("Should raise RegexError"),
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+use regex::{bytes, Regex};
+
+use crate::builder::{CompileOptions, RegexOptions};
+
+/// 캐시가 기본적으로 보관하는 정규표현식의 최대 개수입니다.
+const DEFAULT_CAPACITY: usize = 256;
+
+/// 컴파일된 정규표현식을 찾기 위한 키입니다.
+///
+/// 같은 패턴이라도 순서나 옵션이 다르면 결과가 달라지므로 모두 키에 포함합니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct CacheKey {
+    pub(crate) pattern: String,
+    pub(crate) options: CompileOptions,
+    pub(crate) regex_options: RegexOptions,
+}
+
+/// 크기가 제한된 LRU 캐시입니다.
+///
+/// `Regex`는 내부적으로 참조 카운트를 사용하므로 복제해서 돌려주더라도 컴파일된 결과는 공유됩니다.
+#[derive(Debug)]
+pub(crate) struct Cache<R> {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<CacheKey, (R, u64)>,
+}
+
+impl<R: Clone> Cache<R> {
+    fn new(capacity: usize) -> Self {
+        Cache { capacity, tick: 0, entries: HashMap::new() }
+    }
+
+    fn get(&mut self, key: &CacheKey) -> Option<R> {
+        self.tick += 1;
+        let tick = self.tick;
+        self.entries.get_mut(key).map(|(regex, last_used)| {
+            *last_used = tick;
+            regex.clone()
+        })
+    }
+
+    fn insert(&mut self, key: CacheKey, regex: R) {
+        self.tick += 1;
+        self.entries.insert(key, (regex, self.tick));
+        self.shrink();
+    }
+
+    /// 용량을 넘는 만큼 가장 오래 사용되지 않은 항목부터 지웁니다.
+    fn shrink(&mut self) {
+        while self.entries.len() > self.capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| key.clone())
+                .unwrap();
+            self.entries.remove(&oldest);
+        }
+    }
+}
+
+/// 캐시할 수 있는 정규표현식의 종류입니다. 종류마다 따로 된 캐시를 가집니다.
+pub(crate) trait Cached: Clone + Sized + 'static {
+    fn cache() -> &'static Mutex<Cache<Self>>;
+}
+
+impl Cached for Regex {
+    fn cache() -> &'static Mutex<Cache<Self>> {
+        static CACHE: OnceLock<Mutex<Cache<Regex>>> = OnceLock::new();
+        CACHE.get_or_init(|| Mutex::new(Cache::new(DEFAULT_CAPACITY)))
+    }
+}
+
+impl Cached for bytes::Regex {
+    fn cache() -> &'static Mutex<Cache<Self>> {
+        static CACHE: OnceLock<Mutex<Cache<bytes::Regex>>> = OnceLock::new();
+        CACHE.get_or_init(|| Mutex::new(Cache::new(DEFAULT_CAPACITY)))
+    }
+}
+
+fn lock<R>(cache: &Mutex<Cache<R>>) -> MutexGuard<'_, Cache<R>> {
+    cache.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+/// 캐시에 있는 정규표현식을 돌려주거나, 없다면 `compile`로 컴파일한 뒤 캐시에 넣습니다.
+///
+/// 컴파일에 실패한 결과는 캐시하지 않습니다.
+/// 잠금은 컴파일하는 동안 풀려 있으므로 여러 스레드가 같은 패턴을 동시에 컴파일할 수도 있지만,
+/// 이 경우에도 결과는 같습니다.
+pub(crate) fn get_or_compile<R: Cached, E>(
+    key: CacheKey,
+    compile: impl FnOnce(&CacheKey) -> Result<R, E>,
+) -> Result<R, E> {
+    get_or_compile_in(R::cache(), key, compile)
+}
+
+/// 주어진 캐시를 사용하는 [`get_or_compile`]입니다.
+fn get_or_compile_in<R: Clone, E>(
+    cache: &Mutex<Cache<R>>,
+    key: CacheKey,
+    compile: impl FnOnce(&CacheKey) -> Result<R, E>,
+) -> Result<R, E> {
+    if let Some(regex) = lock(cache).get(&key) {
+        return Ok(regex);
+    }
+    let regex = compile(&key)?;
+    lock(cache).insert(key, regex.clone());
+    Ok(regex)
+}
+
+/// `compile`과 `compile_bytes`가 사용하는 캐시의 최대 크기를 정합니다. 기본값은 256입니다.
+///
+/// 크기는 `Regex`와 `bytes::Regex`의 캐시에 각각 적용됩니다.
+/// 현재 캐시에 들어 있는 항목이 새 크기보다 많다면 가장 오래 사용되지 않은 항목부터 지웁니다.
+/// 0으로 정하면 캐시를 사용하지 않습니다.
+///
+/// ```rust
+/// use korean_regex::*;
+///
+/// set_cache_capacity(1024);
+/// assert_eq!("[각]", compile("[ㄱ:ㅏ:ㄱ]", Order::Default).unwrap().as_str());
+/// ```
+pub fn set_cache_capacity(capacity: usize) {
+    fn set<R: Cached>(capacity: usize) {
+        let mut cache = lock(R::cache());
+        cache.capacity = capacity;
+        cache.shrink();
+    }
+    set::<Regex>(capacity);
+    set::<bytes::Regex>(capacity);
+}
+
+/// `compile`과 `compile_bytes`가 사용하는 캐시를 비웁니다.
+pub fn clear_cache() {
+    lock(Regex::cache()).entries.clear();
+    lock(bytes::Regex::cache()).entries.clear();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Order;
+
+    fn key(pattern: &str) -> CacheKey {
+        CacheKey {
+            pattern: pattern.to_string(),
+            options: CompileOptions::new(Order::Default),
+            regex_options: RegexOptions::default(),
+        }
+    }
+
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let mut cache = Cache::new(2);
+        cache.insert(key("a"), Regex::new("a").unwrap());
+        cache.insert(key("b"), Regex::new("b").unwrap());
+        assert!(cache.get(&key("a")).is_some());
+        cache.insert(key("c"), Regex::new("c").unwrap());
+
+        assert_eq!(2, cache.entries.len());
+        assert!(cache.get(&key("b")).is_none());
+        assert_eq!("a", cache.get(&key("a")).unwrap().as_str());
+        assert_eq!("c", cache.get(&key("c")).unwrap().as_str());
+
+        cache.capacity = 0;
+        cache.shrink();
+        assert!(cache.entries.is_empty());
+    }
+
+    #[test]
+    fn test_get_or_compile() {
+        // 전역 캐시를 건드리지 않도록 따로 만든 캐시를 사용함
+        let cache = Mutex::new(Cache::new(DEFAULT_CAPACITY));
+        let cached = key("[ㄱ:ㅏ:ㅂ]");
+        let first = get_or_compile_in(&cache, cached.clone(), |_| Regex::new("x")).unwrap();
+        // 이미 캐시된 경우 컴파일하지 않음
+        let second =
+            get_or_compile_in(&cache, cached, |_| -> Result<Regex, ()> { panic!("Should be cached") }).unwrap();
+        assert_eq!(first.as_str(), second.as_str());
+
+        let failed = CacheKey { options: CompileOptions::new(Order::RegularFirst), ..key("(") };
+        assert!(get_or_compile_in(&cache, failed.clone(), |key| Regex::new(&key.pattern)).is_err());
+        assert!(get_or_compile_in(&cache, failed, |_| Regex::new("y")).is_ok());
+    }
+}
@@ -0,0 +1,350 @@
+//! 슬롯마다 어떤 음절과 자모가 match되었는지 알려주는 정규표현식입니다.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use regex::{Captures, Match, Regex};
+
+use crate::builder::CompileOptions;
+use crate::hangul::{Chosung, Jungsung, Syllable};
+use crate::reference::slot_group_start;
+use crate::{parser, SlotKind};
+
+/// 패턴 안의 슬롯 하나에 대한 정보입니다.
+#[derive(Debug)]
+struct SlotInfo {
+    span: Range<usize>,
+    /// 초성, 중성, 종성 자리에 붙인 이름입니다.
+    names: [Option<String>; 3],
+}
+
+/// match된 문자열에서 슬롯마다 어떤 음절과 자모가 match되었는지 알려주는 정규표현식입니다.
+///
+/// [`KoreanRegexBuilder::build_captures`](crate::KoreanRegexBuilder::build_captures)로 만듭니다.
+/// 각 슬롯은 이름이 붙은 캡처 그룹으로 감싸져 있기에, [`KoreanRegex::as_regex`]의 캡처 그룹 번호는
+/// 패턴에 적힌 그룹의 번호와 다를 수 있습니다. 패턴에 적힌 번호로 그룹을 찾으려면
+/// [`KoreanRegex::group_index`]나 [`KoreanCaptures::group`]을 사용해 주세요.
+///
+/// ```rust
+/// use korean_regex::*;
+///
+/// let regex = KoreanRegexBuilder::new("[(?<c>ㄱㄴ):ㅏㅣ:(?<t>ㄴㄹ)]다").build_captures().unwrap();
+/// let captures = regex.captures("그날 나는 갈다").unwrap();
+/// assert_eq!("갈다", captures.get_match().as_str());
+/// assert_eq!(Some('ㄱ'), captures.name("c"));
+/// assert_eq!(Some('ㄹ'), captures.name("t"));
+///
+/// let slot = &captures.slots()[0];
+/// assert_eq!("갈", slot.as_str());
+/// assert_eq!(Some(('ㄱ', 'ㅏ', Some('ㄹ'))), slot.syllable().map(|syllable| syllable.decompose()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct KoreanRegex {
+    regex: Regex,
+    slots: Arc<[SlotInfo]>,
+    /// 슬롯을 감싼 캡처 그룹의 번호와 그 슬롯의 순번입니다.
+    groups: Arc<[(usize, usize)]>,
+    /// 패턴에 적힌 캡처 그룹의 번호마다 대응하는 컴파일된 정규표현식의 캡처 그룹 번호입니다.
+    pattern_groups: Arc<[usize]>,
+}
+
+impl KoreanRegex {
+    pub(crate) fn new(regex: Regex, pattern: &str, options: &CompileOptions) -> Self {
+        let slots: Vec<SlotInfo> = parser::parse(pattern, options)
+            .into_iter()
+            .filter_map(|piece| match piece {
+                parser::Piece::Slot(slot) => Some(SlotInfo {
+                    span: slot.span,
+                    names: slot.names.map(|name| name.map(str::to_string)),
+                }),
+                _ => None,
+            })
+            .collect();
+        let groups = regex
+            .capture_names()
+            .enumerate()
+            .filter_map(|(group, name)| {
+                let start = slot_group_start(name?)?;
+                Some((group, slots.iter().position(|slot| slot.span.start == start)?))
+            })
+            .collect();
+        let pattern_groups = regex
+            .capture_names()
+            .enumerate()
+            .filter(|(_, name)| name.and_then(slot_group_start).is_none())
+            .map(|(group, _)| group)
+            .collect();
+        KoreanRegex { regex, slots: slots.into(), groups, pattern_groups }
+    }
+
+    /// 컴파일된 정규표현식을 반환합니다.
+    pub fn as_regex(&self) -> &Regex {
+        &self.regex
+    }
+
+    /// 컴파일된 정규표현식 문자열을 반환합니다.
+    pub fn as_str(&self) -> &str {
+        self.regex.as_str()
+    }
+
+    /// 패턴에 적힌 캡처 그룹의 번호를 컴파일된 정규표현식의 캡처 그룹 번호로 바꿉니다.
+    ///
+    /// 0은 match된 전체 부분을 가리키며, 패턴에 그 번호의 그룹이 없다면 `None`입니다.
+    ///
+    /// ```rust
+    /// use korean_regex::*;
+    ///
+    /// let regex = KoreanRegexBuilder::new("[ㄱ::](a)[ㄴ::](b)").build_captures().unwrap();
+    /// assert_eq!(Some(2), regex.group_index(1));
+    /// assert_eq!(Some(4), regex.group_index(2));
+    /// assert_eq!(None, regex.group_index(3));
+    /// ```
+    pub fn group_index(&self, group: usize) -> Option<usize> {
+        self.pattern_groups.get(group).copied()
+    }
+
+    /// 문자열에 match되는 부분이 있는지 확인합니다.
+    pub fn is_match(&self, haystack: &str) -> bool {
+        self.regex.is_match(haystack)
+    }
+
+    /// 문자열에서 처음으로 match되는 부분과 슬롯마다 match된 부분을 반환합니다.
+    pub fn captures<'h>(&self, haystack: &'h str) -> Option<KoreanCaptures<'h>> {
+        self.regex.captures(haystack).map(|captures| self.wrap(captures))
+    }
+
+    /// 문자열에서 겹치지 않게 match되는 모든 부분을 순회합니다.
+    ///
+    /// ```rust
+    /// use korean_regex::*;
+    ///
+    /// let regex = KoreanRegexBuilder::new("[(?<c>):ㅏ:]").build_captures().unwrap();
+    /// let chosungs: String = regex.captures_iter("가나 달").filter_map(|captures| captures.name("c")).collect();
+    /// assert_eq!("ㄱㄴㄷ", chosungs);
+    /// ```
+    pub fn captures_iter<'a>(&'a self, haystack: &'a str) -> impl Iterator<Item = KoreanCaptures<'a>> + 'a {
+        self.regex.captures_iter(haystack).map(|captures| self.wrap(captures))
+    }
+
+    fn wrap<'h>(&self, captures: Captures<'h>) -> KoreanCaptures<'h> {
+        let mut slots: Vec<SlotMatch> = self
+            .groups
+            .iter()
+            .filter_map(|&(group, index)| {
+                Some(SlotMatch {
+                    index,
+                    pattern_span: self.slots[index].span.clone(),
+                    matched: captures.get(group)?,
+                })
+            })
+            .collect();
+        slots.sort_by_key(|slot| slot.index);
+        KoreanCaptures {
+            captures,
+            slots,
+            infos: Arc::clone(&self.slots),
+            pattern_groups: Arc::clone(&self.pattern_groups),
+        }
+    }
+}
+
+/// [`KoreanRegex`]로 찾은 match 하나입니다.
+#[derive(Debug)]
+pub struct KoreanCaptures<'h> {
+    captures: Captures<'h>,
+    slots: Vec<SlotMatch<'h>>,
+    infos: Arc<[SlotInfo]>,
+    pattern_groups: Arc<[usize]>,
+}
+
+impl<'h> KoreanCaptures<'h> {
+    /// match된 전체 부분을 반환합니다.
+    pub fn get_match(&self) -> Match<'h> {
+        self.captures.get(0).unwrap()
+    }
+
+    /// 정규표현식의 캡처 그룹들을 반환합니다. 슬롯을 감싼 그룹도 포함되어 있습니다.
+    pub fn captures(&self) -> &Captures<'h> {
+        &self.captures
+    }
+
+    /// 패턴에 적힌 번호의 캡처 그룹에 match된 부분을 반환합니다.
+    ///
+    /// 슬롯을 감싼 그룹은 세지 않으며, 그룹이 없거나 match에 참여하지 않았다면 `None`입니다.
+    pub fn group(&self, group: usize) -> Option<Match<'h>> {
+        self.captures.get(*self.pattern_groups.get(group)?)
+    }
+
+    /// match에 참여한 슬롯들을 패턴에 적힌 순서대로 반환합니다.
+    ///
+    /// `?`로 건너뛴 슬롯처럼 match에 참여하지 않은 슬롯은 포함되지 않으며,
+    /// `+`로 반복된 슬롯은 마지막으로 match된 부분만 포함됩니다.
+    pub fn slots(&self) -> &[SlotMatch<'h>] {
+        &self.slots
+    }
+
+    /// `(?<이름>...)`으로 이름을 붙인 자리에 match된 자모를 반환합니다. 같은 이름은 패턴에 한 번만 붙일 수 있습니다.
+    ///
+    /// 그 자리의 슬롯이 match에 참여하지 않았거나, 이름이 붙은 자리가 종성인데 받침이 없다면 `None`입니다.
+    pub fn name(&self, name: &str) -> Option<char> {
+        self.slots.iter().find_map(|slot| {
+            let names = &self.infos[slot.index].names;
+            let position = names.iter().position(|slot_name| slot_name.as_deref() == Some(name))?;
+            slot.jamo([SlotKind::Chosung, SlotKind::Jungsung, SlotKind::Jongsung][position])
+        })
+    }
+}
+
+/// 슬롯 하나에 match된 부분입니다.
+#[derive(Debug, Clone)]
+pub struct SlotMatch<'h> {
+    index: usize,
+    pattern_span: Range<usize>,
+    matched: Match<'h>,
+}
+
+impl<'h> SlotMatch<'h> {
+    /// 이 슬롯이 패턴에서 몇 번째 슬롯인지를 0부터 세어 반환합니다.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// 원래 패턴에서 이 슬롯의 `[`부터 `]`까지의 바이트 범위를 반환합니다.
+    pub fn pattern_span(&self) -> Range<usize> {
+        self.pattern_span.clone()
+    }
+
+    /// 문자열에서 이 슬롯에 match된 부분을 반환합니다.
+    pub fn as_match(&self) -> Match<'h> {
+        self.matched
+    }
+
+    /// 문자열에서 이 슬롯에 match된 문자열을 반환합니다.
+    pub fn as_str(&self) -> &'h str {
+        self.matched.as_str()
+    }
+
+    /// match된 부분이 음절 하나라면 그 음절을 반환합니다.
+    ///
+    /// 첫가끝 자모(U+1100–U+11FF)로 적힌 현대 한글 음절도 음절로 취급합니다.
+    pub fn syllable(&self) -> Option<Syllable> {
+        let mut chars = self.as_str().chars();
+        match (chars.next(), chars.next(), chars.next(), chars.next()) {
+            (Some(chr), None, None, None) => Syllable::from_char(chr),
+            (Some(chosung), Some(jungsung), jongsung, None) => compose_conjoining(chosung, jungsung, jongsung),
+            _ => None,
+        }
+    }
+
+    /// 슬롯의 한 자리에 match된 자모를 반환합니다.
+    ///
+    /// `[ㄱ:0:0]`처럼 자모 하나에 match되는 슬롯이라면 그 자모가 초성이나 중성이 됩니다.
+    /// 종성이 없는 음절의 종성이나, `|` 뒤의 문자처럼 한글이 아닌 부분은 `None`입니다.
+    ///
+    /// ```rust
+    /// use korean_regex::*;
+    ///
+    /// let regex = KoreanRegexBuilder::new("[ㄱ:ㅏ:][ㄱ:0:0]").build_captures().unwrap();
+    /// let captures = regex.captures("간ㄱ").unwrap();
+    /// assert_eq!(Some('ㄴ'), captures.slots()[0].jamo(SlotKind::Jongsung));
+    /// assert_eq!(Some('ㄱ'), captures.slots()[1].jamo(SlotKind::Chosung));
+    /// assert_eq!(None, captures.slots()[1].jamo(SlotKind::Jongsung));
+    /// ```
+    pub fn jamo(&self, kind: SlotKind) -> Option<char> {
+        if let Some(syllable) = self.syllable() {
+            return match kind {
+                SlotKind::Chosung => Some(syllable.chosung()),
+                SlotKind::Jungsung => Some(syllable.jungsung()),
+                SlotKind::Jongsung => syllable.jongsung(),
+            };
+        }
+        let mut chars = self.as_str().chars();
+        let (Some(chr), None) = (chars.next(), chars.next()) else {
+            return None;
+        };
+        let is_kind = match kind {
+            SlotKind::Chosung => Chosung::from_char(chr).is_some(),
+            SlotKind::Jungsung => Jungsung::from_char(chr).is_some(),
+            SlotKind::Jongsung => false,
+        };
+        is_kind.then_some(chr)
+    }
+}
+
+/// 첫가끝 자모로 적힌 현대 한글 음절을 완성형 음절로 바꿉니다.
+fn compose_conjoining(chosung: char, jungsung: char, jongsung: Option<char>) -> Option<Syllable> {
+    let chosung = (chosung as u32).checked_sub(0x1100).filter(|index| *index < 19)?;
+    let jungsung = (jungsung as u32).checked_sub(0x1161).filter(|index| *index < 21)?;
+    let jongsung = match jongsung {
+        Some(jongsung) => (jongsung as u32).checked_sub(0x11A7).filter(|index| (1..28).contains(index))?,
+        None => 0,
+    };
+    char::from_u32(0xAC00 + 588 * chosung + 28 * jungsung + jongsung).and_then(Syllable::from_char)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_captures() {
+        let pattern = "^[ㄱ::]?[(?<c>ㄴㄷ):(?<j>):(?<t>)]+([ㅎ::])$";
+        let regex = KoreanRegexBuilder::new(pattern).build_captures().unwrap();
+        let captures = regex.captures("나닭흙").unwrap();
+        let slots: Vec<_> = captures.slots().iter().map(|slot| (slot.index(), slot.as_str())).collect();
+        // 건너뛴 슬롯은 없고 반복된 슬롯은 마지막 부분만 있음
+        assert_eq!(vec![(1, "닭"), (2, "흙")], slots);
+        assert_eq!(Some('ㄷ'), captures.name("c"));
+        assert_eq!(Some('ㅏ'), captures.name("j"));
+        assert_eq!(Some('ㄺ'), captures.name("t"));
+        assert_eq!(None, captures.name("x"));
+        assert_eq!("[(?<c>ㄴㄷ):(?<j>):(?<t>)]", &pattern[captures.slots()[0].pattern_span()]);
+        // 패턴의 캡처 그룹은 슬롯을 감싼 그룹을 세지 않은 번호로 찾음
+        assert_eq!(Some(3), regex.group_index(1));
+        assert_eq!("흙", &captures.captures()[3]);
+        assert_eq!(Some("흙"), captures.group(1).map(|group| group.as_str()));
+        assert_eq!(Some("나닭흙"), captures.group(0).map(|group| group.as_str()));
+        assert_eq!(None, captures.group(2));
+
+        // 받침이 없다면 종성은 없음
+        assert_eq!(None, regex.captures("나흙").unwrap().name("t"));
+    }
+
+    #[test]
+    fn test_captures_with_references() {
+        let regex = KoreanRegexBuilder::new("[(?<c>ㄱㄴ<a>)::][\\k<a>:ㅣ:]").build_captures().unwrap();
+        let captures = regex.captures("나니").unwrap();
+        assert_eq!(Some('ㄴ'), captures.name("c"));
+        assert_eq!(vec![0, 1], captures.slots().iter().map(|slot| slot.index()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_captures_with_conjoining() {
+        let regex = KoreanRegexBuilder::new("[(?<c>ㄱ):ㅏ:(?<t>)]").conjoining(true).build_captures().unwrap();
+        let captures = regex.captures("\u{1100}\u{1161}\u{11AB}").unwrap();
+        assert_eq!(Some('ㄱ'), captures.name("c"));
+        assert_eq!(Some('ㄴ'), captures.name("t"));
+        assert_eq!(Some('간'), captures.slots()[0].syllable().map(|syllable| syllable.to_char()));
+    }
+
+    #[test]
+    fn test_duplicate_slot_names() {
+        for pattern in ["[(?<c>ㄱ):(?<c>ㅏ)]", "[(?<c>ㄱ)::]x[(?<c>ㄴ)::]"] {
+            let error = KoreanRegexBuilder::new(pattern).build_captures().unwrap_err();
+            assert_eq!(ErrorKind::InvalidSlot, error.kind());
+            assert_eq!("(?<c>", &pattern[error.span()]);
+            // 캡처 API를 쓰지 않아도 오류임
+            assert!(compilestr(pattern, Order::Default).is_err());
+        }
+        // 자모가 없어도 이름이 붙은 자리는 슬롯이기에 오류임
+        assert!(compilestr("[(?<c>):][(?<c>):]", Order::Default).is_err());
+    }
+
+    #[test]
+    fn test_named_part_without_jamo() {
+        let regex = KoreanRegexBuilder::new("[(?<c>)::]").build_captures().unwrap();
+        let captures = regex.captures("a한").unwrap();
+        assert_eq!("한", captures.get_match().as_str());
+        assert_eq!(Some('ㅎ'), captures.name("c"));
+    }
+}
@@ -0,0 +1,422 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+use crate::SlotKind;
+
+/// korean-regex에서 나올 수 있는 모든 오류를 모아놓은 enum입니다.
+///
+/// 모든 오류는 마지막 값으로 원래 패턴 안에서 오류가 난 위치의 바이트 범위를 가집니다.
+/// [`substitute`](crate::substitute)에서 난 오류의 범위는 `[초성:중성:종성]` 형태로
+/// 이어 붙인 문자열을 기준으로 합니다.
+///
+/// 슬롯의 한 자리에서 난 오류는 그 자리가 초성, 중성, 종성 중 어디인지를 `Option<SlotKind>`로 함께 가집니다.
+///
+/// 오류의 종류에 따라 분기할 때는 variant를 직접 match하는 대신 [`KoreanRegexError::kind`]를 사용하면
+/// 이후 버전에서 variant가 추가되더라도 코드가 깨지지 않습니다.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum KoreanRegexError {
+    /// 괄호로 묶인 문자를 합치는 것에 실패했을 때 나타나는 오류입니다.
+    /// 예를 들어 `(ㄱㅇ)`는 적절하지 않은 괄호 문법이기에 오류를 냅니다.
+    UnparenthesizingFailedError(String, Option<SlotKind>, Range<usize>),
+    /// 하이픈이 맨 앞이나 맨 뒤에 나오거나 문자 인덱스가 잘못되었을 경우 발생합니다.
+    /// 예를 들어 `[-ㅅ::]`는 하이픈의 앞에 해당하는 문자가 없기에 오류를 냅니다.
+    /// 또한 `[ㅅ-ㄱ::]`은 하이픈의 앞의 문자가 뒤의 문자보다 인덱스가 더 크기에 오류가 납니다.
+    InvalidHyphenError(String, Option<SlotKind>, Range<usize>),
+    /// 0은 종성이 위치에서는 올 수 있지만 초성이나 중성에서는 제한적이게만 가능합니다.
+    /// 예를 들어 `[0ㄱ:ㅏ0:ㄱ]`같이 초성이나 중성에는 0을 섞어서 쓸 수 없으며 오직
+    /// 0이거나 0이 아닌 문자들이거나 둘 중 하나만 가능합니다.
+    /// 또한 중성이 없는 한글은 없기에 `[ㄱ:0:ㄱ]`같은 패턴 또한 불가능하며
+    /// 초성이나 중성에 문자가 들어갈 수 있는 패턴은 오직 `[*:0:0]`이나 `[0:*:0]`뿐입니다.
+    /// 이 규칙을 어겼을 경우 이 오류가 납니다.
+    InvalidZeroPatternError(String, Range<usize>),
+    /// 한글 음소가 아닌 글자가 왔을 경우 발생합니다. 예를 들어 `[d:ㅏ:ㄴ]`은 이 오류를 발생시킵니다.
+    InvalidPhonemeError(String, char, Option<SlotKind>, Range<usize>),
+    /// 엄격 모드에서 슬롯처럼 보이지만 슬롯 문법에 맞지 않거나, 어떤 문자에도 match할 수 없는 슬롯이 있을 경우 발생합니다.
+    /// 예를 들어 `[ㄱ:ㅏ:ㄴ:ㄷ]`는 `:`가 너무 많기에, `[^ㄱ-ㅎ::]`는 match할 수 있는 문자가 없기에 오류를 냅니다.
+    /// 비어 있는 초성 검색 문법 `\c{}`와 `(?<이름>...)`으로 같은 이름을 두 번 붙인 슬롯도 엄격 모드와 관계없이 이 오류를 냅니다.
+    InvalidSlotError(String, Range<usize>),
+    /// `@경음`과 같이 이름이 붙은 자모 집합을 찾을 수 없거나, 그 집합에 해당 자리에 올 수 있는 자모가 없을 경우 발생합니다.
+    /// 예를 들어 `[@없는집합:ㅏ:]`은 없는 집합이기에, `[@이중모음:ㅏ:]`은 초성이 될 수 있는 이중 모음이 없기에 오류를 냅니다.
+    InvalidSetError(String, Option<SlotKind>, Range<usize>),
+    /// 자모를 이름에 묶는 `<이름>`이나 묶인 자모를 다시 요구하는 `\k<이름>`을 잘못 사용했을 경우 발생합니다.
+    /// 예를 들어 `[\k<a>::]`는 앞에서 `a`를 묶지 않았기에, `[<a>::]|[\k<a>::]`는 묶은 곳과 요구하는 곳이
+    /// `|`로 나뉘어 있기에 오류를 냅니다.
+    InvalidReferenceError(String, Range<usize>),
+    /// compile 함수에서 regex 관련 오류가 일어났을 경우 사용됩니다.
+    ///
+    /// 컴파일된 정규표현식의 어느 부분이 원래 패턴의 어디에 해당하는지 알 수 없기에
+    /// 범위는 패턴 전체를 가리킵니다. `From<regex::Error>`로 만들어진 경우에는 빈 범위(`0..0`)입니다.
+    RegexError(regex::Error, Range<usize>),
+}
+
+/// [`KoreanRegexError`]의 종류입니다.
+///
+/// 새로운 종류의 오류가 추가될 수 있기에 match할 때에는 항상 `_` 갈래가 필요합니다.
+///
+/// ```rust
+/// use korean_regex::*;
+///
+/// let error = compilestr("[ㄱ:(ㅗㅗ):]", Order::Default).unwrap_err();
+/// assert_eq!(ErrorKind::UnparenthesizingFailed, error.kind());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// [`KoreanRegexError::UnparenthesizingFailedError`]
+    UnparenthesizingFailed,
+    /// [`KoreanRegexError::InvalidHyphenError`]
+    InvalidHyphen,
+    /// [`KoreanRegexError::InvalidZeroPatternError`]
+    InvalidZeroPattern,
+    /// [`KoreanRegexError::InvalidPhonemeError`]
+    InvalidPhoneme,
+    /// [`KoreanRegexError::InvalidSlotError`]
+    InvalidSlot,
+    /// [`KoreanRegexError::InvalidSetError`]
+    InvalidSet,
+    /// [`KoreanRegexError::InvalidReferenceError`]
+    InvalidReference,
+    /// [`KoreanRegexError::RegexError`]
+    Regex,
+}
+
+impl KoreanRegexError {
+    /// 원래 패턴 안에서 오류가 난 위치의 바이트 범위를 반환합니다.
+    ///
+    /// ```rust
+    /// use korean_regex::*;
+    ///
+    /// let pattern = "[ㄱ::][ㄴ:(ㅗㅗ):]";
+    /// let error = compilestr(pattern, Order::Default).unwrap_err();
+    /// assert_eq!("(ㅗㅗ)", &pattern[error.span()]);
+    /// ```
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            KoreanRegexError::UnparenthesizingFailedError(_, _, span)
+            | KoreanRegexError::InvalidHyphenError(_, _, span)
+            | KoreanRegexError::InvalidZeroPatternError(_, span)
+            | KoreanRegexError::InvalidPhonemeError(_, _, _, span)
+            | KoreanRegexError::InvalidSlotError(_, span)
+            | KoreanRegexError::InvalidSetError(_, _, span)
+            | KoreanRegexError::InvalidReferenceError(_, span)
+            | KoreanRegexError::RegexError(_, span) => span.clone(),
+        }
+    }
+
+    /// 오류의 종류를 반환합니다.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            KoreanRegexError::UnparenthesizingFailedError(..) => ErrorKind::UnparenthesizingFailed,
+            KoreanRegexError::InvalidHyphenError(..) => ErrorKind::InvalidHyphen,
+            KoreanRegexError::InvalidZeroPatternError(..) => ErrorKind::InvalidZeroPattern,
+            KoreanRegexError::InvalidPhonemeError(..) => ErrorKind::InvalidPhoneme,
+            KoreanRegexError::InvalidSlotError(..) => ErrorKind::InvalidSlot,
+            KoreanRegexError::InvalidSetError(..) => ErrorKind::InvalidSet,
+            KoreanRegexError::InvalidReferenceError(..) => ErrorKind::InvalidReference,
+            KoreanRegexError::RegexError(..) => ErrorKind::Regex,
+        }
+    }
+
+    /// 오류를 일으킨 문자가 있다면 그 문자를 반환합니다.
+    ///
+    /// ```rust
+    /// use korean_regex::*;
+    ///
+    /// let error = compilestr_strict("[ㅏ::]", Order::Default).unwrap_err();
+    /// assert_eq!(Some('ㅏ'), error.phoneme());
+    /// assert_eq!(Some(SlotKind::Chosung), error.slot());
+    /// ```
+    pub fn phoneme(&self) -> Option<char> {
+        match self {
+            KoreanRegexError::InvalidPhonemeError(_, phoneme, _, _) => Some(*phoneme),
+            _ => None,
+        }
+    }
+
+    /// 오류가 슬롯의 초성, 중성, 종성 중 한 자리에서 났다면 그 자리를 반환합니다.
+    ///
+    /// ```rust
+    /// use korean_regex::*;
+    ///
+    /// let error = compilestr("[ㄱ::ㄹ-ㄱ]", Order::Default).unwrap_err();
+    /// assert_eq!(Some(SlotKind::Jongsung), error.slot());
+    /// ```
+    pub fn slot(&self) -> Option<SlotKind> {
+        match self {
+            KoreanRegexError::UnparenthesizingFailedError(_, slot, _)
+            | KoreanRegexError::InvalidHyphenError(_, slot, _)
+            | KoreanRegexError::InvalidPhonemeError(_, _, slot, _)
+            | KoreanRegexError::InvalidSetError(_, slot, _) => *slot,
+            _ => None,
+        }
+    }
+
+    /// 패턴을 출력하고 그 아래에 오류가 난 위치를 `^`로 표시한 문자열을 반환합니다.
+    ///
+    /// 한글처럼 터미널에서 두 칸을 차지하는 문자는 `^` 또한 두 칸으로 표시됩니다.
+    ///
+    /// ```rust
+    /// use korean_regex::*;
+    ///
+    /// let pattern = "[ㄱ::][ㄴ:(ㅗㅗ):]";
+    /// let error = compilestr(pattern, Order::Default).unwrap_err();
+    /// let rendered = error.render(pattern);
+    /// let lines: Vec<_> = rendered.lines().collect();
+    /// assert_eq!("korean regex parse error:", lines[0]);
+    /// assert_eq!("    [ㄱ::][ㄴ:(ㅗㅗ):]", lines[1]);
+    /// assert_eq!("              ^^^^^^", lines[2]);
+    /// assert_eq!("error: Invalid Syntax: Unknown item inside parenthesis(ㅗㅗ).", lines[3]);
+    /// ```
+    pub fn render(&self, pattern: &str) -> String {
+        let span = self.span();
+        let mut rendered = String::from("korean regex parse error:\n");
+
+        let mut line_start = 0;
+        for line in pattern.split('\n') {
+            let line_end = line_start + line.len();
+            let overlaps = if span.is_empty() {
+                (line_start..=line_end).contains(&span.start)
+            } else {
+                span.start < line_end && line_start < span.end
+            };
+
+            if overlaps {
+                let mut underline = String::new();
+                for (index, chr) in line.char_indices() {
+                    let offset = line_start + index;
+                    if span.is_empty() && offset == span.start {
+                        break;
+                    }
+                    let mark = if span.contains(&offset) { "^" } else { " " };
+                    underline.push_str(&mark.repeat(display_width(chr)));
+                }
+                if span.is_empty() {
+                    underline.push('^');
+                }
+
+                rendered.push_str("    ");
+                rendered.push_str(line);
+                rendered.push_str("\n    ");
+                rendered.push_str(underline.trim_end());
+                rendered.push('\n');
+            }
+            line_start = line_end + 1;
+        }
+
+        rendered.push_str("error: ");
+        rendered.push_str(&self.message());
+        rendered
+    }
+
+    /// 위치 정보를 제외한 오류 메시지입니다.
+    pub(crate) fn message(&self) -> String {
+        match self {
+            KoreanRegexError::UnparenthesizingFailedError(message, _, _)
+            | KoreanRegexError::InvalidHyphenError(message, _, _)
+            | KoreanRegexError::InvalidZeroPatternError(message, _)
+            | KoreanRegexError::InvalidPhonemeError(message, _, _, _)
+            | KoreanRegexError::InvalidSlotError(message, _)
+            | KoreanRegexError::InvalidSetError(message, _, _)
+            | KoreanRegexError::InvalidReferenceError(message, _) => message.clone(),
+            KoreanRegexError::RegexError(error, _) => error.to_string(),
+        }
+    }
+
+    /// 오류의 위치를 `offset`만큼 뒤로 옮깁니다.
+    pub(crate) fn offset(mut self, offset: usize) -> Self {
+        match &mut self {
+            KoreanRegexError::UnparenthesizingFailedError(_, _, span)
+            | KoreanRegexError::InvalidHyphenError(_, _, span)
+            | KoreanRegexError::InvalidZeroPatternError(_, span)
+            | KoreanRegexError::InvalidPhonemeError(_, _, _, span)
+            | KoreanRegexError::InvalidSlotError(_, span)
+            | KoreanRegexError::InvalidSetError(_, _, span)
+            | KoreanRegexError::InvalidReferenceError(_, span)
+            | KoreanRegexError::RegexError(_, span) => {
+                *span = span.start + offset..span.end + offset;
+            }
+        }
+        self
+    }
+
+    /// 슬롯의 한 자리에서 난 오류에 그 자리를 기록합니다.
+    pub(crate) fn in_slot(mut self, kind: SlotKind) -> Self {
+        match &mut self {
+            KoreanRegexError::UnparenthesizingFailedError(_, slot, _)
+            | KoreanRegexError::InvalidHyphenError(_, slot, _)
+            | KoreanRegexError::InvalidPhonemeError(_, _, slot, _)
+            | KoreanRegexError::InvalidSetError(_, slot, _) => *slot = Some(kind),
+            _ => (),
+        }
+        self
+    }
+}
+
+impl fmt::Display for KoreanRegexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // 정규표현식 오류의 내용은 `source`로 전달되기에 여기서는 반복하지 않음
+        let message = match self {
+            KoreanRegexError::RegexError(..) => "Failed to compile the generated regex.".to_string(),
+            _ => self.message(),
+        };
+        write!(f, "{message} (at {}..{})", self.span().start, self.span().end)
+    }
+}
+
+impl Error for KoreanRegexError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            KoreanRegexError::RegexError(error, _) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<regex::Error> for KoreanRegexError {
+    fn from(error: regex::Error) -> Self {
+        KoreanRegexError::RegexError(error, 0..0)
+    }
+}
+
+/// [`compile_set`](crate::compile_set)에서 난 오류입니다.
+///
+/// 어떤 패턴에서 오류가 났는지를 패턴의 인덱스로 함께 가집니다.
+/// 오류의 위치([`KoreanRegexError::span`])는 해당 패턴을 기준으로 합니다.
+///
+/// ```rust
+/// use korean_regex::*;
+///
+/// let patterns = ["[ㄱ::]", "[ㄴ:(ㅗㅗ):]"];
+/// let error = compile_set(patterns, Order::Default).unwrap_err();
+/// assert_eq!(Some(1), error.index());
+/// assert_eq!("(ㅗㅗ)", &patterns[1][error.error().span()]);
+/// ```
+#[derive(Debug)]
+pub struct KoreanRegexSetError {
+    index: Option<usize>,
+    error: KoreanRegexError,
+}
+
+impl KoreanRegexSetError {
+    pub(crate) fn new(index: Option<usize>, error: KoreanRegexError) -> Self {
+        KoreanRegexSetError { index, error }
+    }
+
+    /// 오류가 난 패턴의 인덱스를 반환합니다.
+    ///
+    /// 크기 제한을 넘는 등 특정 패턴이 아닌 패턴 집합 전체의 문제라면 `None`입니다.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// 패턴에서 난 오류를 반환합니다.
+    pub fn error(&self) -> &KoreanRegexError {
+        &self.error
+    }
+
+    /// 패턴에서 난 오류를 꺼냅니다.
+    pub fn into_error(self) -> KoreanRegexError {
+        self.error
+    }
+}
+
+impl fmt::Display for KoreanRegexSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.index {
+            Some(index) => write!(f, "pattern {index}: {}", self.error),
+            None => self.error.fmt(f),
+        }
+    }
+}
+
+impl Error for KoreanRegexSetError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// 고정폭 터미널에서 문자가 차지하는 칸 수입니다.
+///
+/// 한글과 한자, 전각 문자 등 동아시아 문자는 두 칸을 차지합니다.
+fn display_width(chr: char) -> usize {
+    match chr {
+        '\u{1100}'..='\u{115F}'
+        | '\u{2E80}'..='\u{303E}'
+        | '\u{3041}'..='\u{33FF}'
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{A960}'..='\u{A97F}'
+        | '\u{AC00}'..='\u{D7A3}'
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{FE30}'..='\u{FE4F}'
+        | '\u{FF00}'..='\u{FF60}'
+        | '\u{FFE0}'..='\u{FFE6}' => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn hyphen_error(span: Range<usize>) -> KoreanRegexError {
+        KoreanRegexError::InvalidHyphenError("Position of hyphen is invalid.".to_string(), None, span)
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            "korean regex parse error:\n    ab[-ㄱ::]\n       ^\nerror: Position of hyphen is invalid.",
+            hyphen_error(3..4).render("ab[-ㄱ::]")
+        );
+        assert_eq!(
+            "korean regex parse error:\n    [ㄱ-::]\n       ^\nerror: Position of hyphen is invalid.",
+            hyphen_error(4..5).render("[ㄱ-::]")
+        );
+
+        // 여러 줄로 된 패턴에서는 오류가 난 줄만 보여줌
+        assert_eq!(
+            "korean regex parse error:\n    [ㄱ-::]\n       ^\nerror: Position of hyphen is invalid.",
+            hyphen_error(9..10).render("abcd\n[ㄱ-::]\nefg")
+        );
+
+        // 빈 범위는 한 칸으로 표시
+        assert_eq!(
+            "korean regex parse error:\n    abc\n       ^\nerror: Position of hyphen is invalid.",
+            hyphen_error(3..3).render("abc")
+        );
+    }
+
+    #[test]
+    fn test_error_trait() {
+        fn question_mark() -> Result<(), Box<dyn Error>> {
+            crate::compile("[ㄱ:ㅏ", crate::Order::Default)?;
+            Ok(())
+        }
+        assert!(question_mark().is_err());
+
+        let error = hyphen_error(3..4);
+        assert_eq!("Position of hyphen is invalid. (at 3..4)", error.to_string());
+        assert!(error.source().is_none());
+        assert_eq!(ErrorKind::InvalidHyphen, error.kind());
+        assert_eq!(None, error.phoneme());
+        assert_eq!(Some(SlotKind::Jungsung), error.in_slot(SlotKind::Jungsung).slot());
+
+        let unclosed_group = "(".to_string();
+        let regex_error = regex::Regex::new(&unclosed_group).unwrap_err();
+        let error = KoreanRegexError::from(regex_error.clone());
+        assert_eq!(ErrorKind::Regex, error.kind());
+        assert_eq!("Failed to compile the generated regex. (at 0..0)", error.to_string());
+        assert_eq!(
+            regex_error.to_string(),
+            error.source().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn test_offset() {
+        assert_eq!(13..14, hyphen_error(3..4).offset(10).span());
+    }
+}
@@ -0,0 +1,811 @@
+//! 한글 음절을 초성, 중성, 종성으로 조합하고 분해하는 도구들입니다.
+//!
+//! ```rust
+//! use korean_regex::hangul::Syllable;
+//!
+//! let syllable = Syllable::from_char('각').unwrap();
+//! assert_eq!(('ㄱ', 'ㅏ', Some('ㄱ')), syllable.decompose());
+//! assert_eq!('강', syllable.with_jongsung(Some('ㅇ')).unwrap().to_char());
+//! ```
+
+use std::fmt;
+
+use crate::substitute::convert_phonemes_to_syllable;
+use crate::{KoreanRegexError, Order, SlotKind};
+
+/// 한글 음절 중 첫 번째 글자인 `가`의 코드 포인트입니다.
+const SYLLABLE_START: u32 = 0xAC00;
+/// 한글 음절의 개수입니다.
+const SYLLABLE_COUNT: u32 = 11172;
+
+/// 두 개의 자모로 이루어진 모든 겹자모와 그 구성입니다.
+///
+/// 된소리(`ㄲ` 등), 겹받침(`ㄺ` 등), 이중 모음(`ㅘ` 등)을 모두 포함합니다.
+/// 패턴의 `(ㄹㄱ)`와 같은 괄호 문법도 이 표를 사용합니다.
+pub const COMPOUND_JAMO: [(char, (char, char)); 23] = [
+    ('ㄲ', ('ㄱ', 'ㄱ')),
+    ('ㄸ', ('ㄷ', 'ㄷ')),
+    ('ㅃ', ('ㅂ', 'ㅂ')),
+    ('ㅆ', ('ㅅ', 'ㅅ')),
+    ('ㅉ', ('ㅈ', 'ㅈ')),
+    ('ㄳ', ('ㄱ', 'ㅅ')),
+    ('ㄵ', ('ㄴ', 'ㅈ')),
+    ('ㄶ', ('ㄴ', 'ㅎ')),
+    ('ㄺ', ('ㄹ', 'ㄱ')),
+    ('ㄻ', ('ㄹ', 'ㅁ')),
+    ('ㄼ', ('ㄹ', 'ㅂ')),
+    ('ㄽ', ('ㄹ', 'ㅅ')),
+    ('ㄾ', ('ㄹ', 'ㅌ')),
+    ('ㄿ', ('ㄹ', 'ㅍ')),
+    ('ㅀ', ('ㄹ', 'ㅎ')),
+    ('ㅄ', ('ㅂ', 'ㅅ')),
+    ('ㅘ', ('ㅗ', 'ㅏ')),
+    ('ㅙ', ('ㅗ', 'ㅐ')),
+    ('ㅚ', ('ㅗ', 'ㅣ')),
+    ('ㅝ', ('ㅜ', 'ㅓ')),
+    ('ㅞ', ('ㅜ', 'ㅔ')),
+    ('ㅟ', ('ㅜ', 'ㅣ')),
+    ('ㅢ', ('ㅡ', 'ㅣ')),
+];
+
+/// 슬롯 안에서 `@경음`처럼 이름으로 쓸 수 있는 자모 집합들입니다.
+///
+/// 슬롯에서는 집합 중 그 자리에 올 수 있는 자모만 사용됩니다.
+/// 예를 들어 `@경음`은 초성에서는 `ㄲㄸㅃㅆㅉ`이지만 종성에서는 `ㄲㅆ`입니다.
+/// `ㅡ`는 음성 모음으로, `ㅣ`는 중성 모음으로 취급합니다.
+pub const NAMED_JAMO_SETS: [(&str, &[char]); 8] = [
+    ("경음", &['ㄲ', 'ㄸ', 'ㅃ', 'ㅆ', 'ㅉ']),
+    ("격음", &['ㅊ', 'ㅋ', 'ㅌ', 'ㅍ']),
+    ("평음", &['ㄱ', 'ㄷ', 'ㅂ', 'ㅅ', 'ㅈ']),
+    ("홑받침", &['ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ']),
+    ("겹받침", &['ㄳ', 'ㄵ', 'ㄶ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅄ']),
+    ("이중모음", &['ㅑ', 'ㅒ', 'ㅕ', 'ㅖ', 'ㅘ', 'ㅙ', 'ㅛ', 'ㅝ', 'ㅞ', 'ㅠ', 'ㅢ']),
+    ("양성모음", &['ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ']),
+    ("음성모음", &['ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅜ', 'ㅝ', 'ㅞ', 'ㅟ', 'ㅠ', 'ㅡ']),
+];
+
+/// 이름이 붙은 자모 집합을 찾습니다. 이름 앞의 `@`는 제외합니다.
+///
+/// ```rust
+/// use korean_regex::hangul::named_jamo_set;
+///
+/// assert_eq!(Some(&['ㄲ', 'ㄸ', 'ㅃ', 'ㅆ', 'ㅉ'][..]), named_jamo_set("경음"));
+/// assert_eq!(None, named_jamo_set("없는집합"));
+/// ```
+pub fn named_jamo_set(name: &str) -> Option<&'static [char]> {
+    NAMED_JAMO_SETS
+        .iter()
+        .find(|(set_name, _)| *set_name == name)
+        .map(|(_, jamos)| *jamos)
+}
+
+/// 두 자모를 합친 겹자모를 반환합니다. 합칠 수 없다면 `None`입니다.
+///
+/// ```rust
+/// use korean_regex::hangul::compose_jamo;
+///
+/// assert_eq!(Some('ㄺ'), compose_jamo('ㄹ', 'ㄱ'));
+/// assert_eq!(Some('ㅙ'), compose_jamo('ㅗ', 'ㅐ'));
+/// assert_eq!(None, compose_jamo('ㄱ', 'ㄹ'));
+/// ```
+pub fn compose_jamo(first: char, second: char) -> Option<char> {
+    COMPOUND_JAMO
+        .iter()
+        .find(|(_, parts)| *parts == (first, second))
+        .map(|(compound, _)| *compound)
+}
+
+/// 겹자모를 두 자모로 나눕니다. 겹자모가 아니라면 `None`입니다.
+///
+/// ```rust
+/// use korean_regex::hangul::decompose_jamo;
+///
+/// assert_eq!(Some(('ㄹ', 'ㄱ')), decompose_jamo('ㄺ'));
+/// assert_eq!(Some(('ㅗ', 'ㅐ')), decompose_jamo('ㅙ'));
+/// assert_eq!(None, decompose_jamo('ㄱ'));
+/// ```
+pub fn decompose_jamo(compound: char) -> Option<(char, char)> {
+    COMPOUND_JAMO
+        .iter()
+        .find(|(chr, _)| *chr == compound)
+        .map(|(_, parts)| *parts)
+}
+
+/// (호환용 자모, 첫가끝 초성, 첫가끝 중성, 첫가끝 종성)
+pub(crate) type ArchaicJamo = (char, Option<char>, Option<char>, Option<char>);
+
+/// 옛한글 호환용 자모와 그에 대응하는 (첫가끝 초성, 중성, 종성)입니다.
+///
+/// 옛한글은 완성형 음절이 없기에 첫가끝 자모의 나열로만 적을 수 있습니다.
+/// 어떤 자리에 올 수 없는 자모는 해당 값이 `None`입니다.
+pub(crate) const ARCHAIC_JAMO: [ArchaicJamo; 42] = [
+    ('ㅥ', Some('\u{1114}'), None, Some('\u{11FF}')),
+    ('ㅦ', Some('\u{1115}'), None, Some('\u{11C6}')),
+    ('ㅧ', Some('\u{115B}'), None, Some('\u{11C7}')),
+    ('ㅨ', None, None, Some('\u{11C8}')),
+    ('ㅩ', None, None, Some('\u{11CC}')),
+    ('ㅪ', Some('\u{A966}'), None, Some('\u{11CE}')),
+    ('ㅫ', None, None, Some('\u{11D3}')),
+    ('ㅬ', None, None, Some('\u{11D7}')),
+    ('ㅭ', None, None, Some('\u{11D9}')),
+    ('ㅮ', Some('\u{111C}'), None, Some('\u{11DC}')),
+    ('ㅯ', Some('\u{A971}'), None, Some('\u{11DD}')),
+    ('ㅰ', None, None, Some('\u{11DF}')),
+    ('ㅱ', Some('\u{111D}'), None, Some('\u{11E2}')),
+    ('ㅲ', Some('\u{111E}'), None, None),
+    ('ㅳ', Some('\u{1120}'), None, Some('\u{D7E3}')),
+    ('ㅴ', Some('\u{1122}'), None, None),
+    ('ㅵ', Some('\u{1123}'), None, Some('\u{D7E7}')),
+    ('ㅶ', Some('\u{1127}'), None, Some('\u{D7E8}')),
+    ('ㅷ', Some('\u{1129}'), None, None),
+    ('ㅸ', Some('\u{112B}'), None, Some('\u{11E6}')),
+    ('ㅹ', Some('\u{112C}'), None, None),
+    ('ㅺ', Some('\u{112D}'), None, Some('\u{11E7}')),
+    ('ㅻ', Some('\u{112E}'), None, None),
+    ('ㅼ', Some('\u{112F}'), None, Some('\u{11E8}')),
+    ('ㅽ', Some('\u{1132}'), None, Some('\u{11EA}')),
+    ('ㅾ', Some('\u{1136}'), None, Some('\u{D7EF}')),
+    ('ㅿ', Some('\u{1140}'), None, Some('\u{11EB}')),
+    ('ㆀ', Some('\u{1147}'), None, Some('\u{11EE}')),
+    ('ㆁ', Some('\u{114C}'), None, Some('\u{11F0}')),
+    ('ㆂ', None, None, Some('\u{11F1}')),
+    ('ㆃ', None, None, Some('\u{11F2}')),
+    ('ㆄ', Some('\u{1157}'), None, Some('\u{11F4}')),
+    ('ㆅ', Some('\u{1158}'), None, None),
+    ('ㆆ', Some('\u{1159}'), None, Some('\u{11F9}')),
+    ('ㆇ', None, Some('\u{1184}'), None),
+    ('ㆈ', None, Some('\u{1185}'), None),
+    ('ㆉ', None, Some('\u{1188}'), None),
+    ('ㆊ', None, Some('\u{1191}'), None),
+    ('ㆋ', None, Some('\u{1192}'), None),
+    ('ㆌ', None, Some('\u{1194}'), None),
+    ('ㆍ', None, Some('\u{119E}'), None),
+    ('ㆎ', None, Some('\u{11A1}'), None),
+];
+
+/// 호환용 자모를 주어진 자리의 첫가끝 자모(U+1100–U+11FF, U+A960–U+A97F, U+D7B0–U+D7FF)로 바꿉니다.
+///
+/// 현대 한글 자모와 옛한글 자모를 모두 지원하며, 해당 자리에 올 수 없는 자모라면 `None`입니다.
+///
+/// ```rust
+/// use korean_regex::hangul::conjoining_jamo;
+/// use korean_regex::SlotKind;
+///
+/// assert_eq!(Some('\u{1100}'), conjoining_jamo('ㄱ', SlotKind::Chosung));
+/// assert_eq!(Some('\u{11A8}'), conjoining_jamo('ㄱ', SlotKind::Jongsung));
+/// assert_eq!(Some('\u{119E}'), conjoining_jamo('ㆍ', SlotKind::Jungsung));
+/// assert_eq!(None, conjoining_jamo('ㄸ', SlotKind::Jongsung));
+/// ```
+pub fn conjoining_jamo(chr: char, kind: SlotKind) -> Option<char> {
+    let modern = match kind {
+        SlotKind::Chosung => Chosung::from_char(chr).map(|jamo| 0x1100 + jamo.index(Order::Default)),
+        SlotKind::Jungsung => Jungsung::from_char(chr).map(|jamo| 0x1161 + jamo.index(Order::Default)),
+        SlotKind::Jongsung => Jongsung::from_char(chr).map(|jamo| 0x11A8 + jamo.index(Order::Default)),
+    };
+    if let Some(code) = modern {
+        return char::from_u32(code as u32);
+    }
+    ARCHAIC_JAMO
+        .iter()
+        .find(|(jamo, ..)| *jamo == chr)
+        .and_then(|(_, chosung, jungsung, jongsung)| match kind {
+            SlotKind::Chosung => *chosung,
+            SlotKind::Jungsung => *jungsung,
+            SlotKind::Jongsung => *jongsung,
+        })
+}
+
+/// 괄호나 원으로 둘러싸인 형태가 있는 자음입니다. (`㈀`-`㈍`, `㉠`-`㉭`)
+const ENCLOSED_CONSONANTS: [char; 14] = [
+    'ㄱ', 'ㄴ', 'ㄷ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅅ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+/// 괄호나 원으로 둘러싸인 형태가 있는 음절입니다. (`㈎`-`㈛`, `㉮`-`㉻`)
+const ENCLOSED_SYLLABLES: [char; 14] = [
+    '가', '나', '다', '라', '마', '바', '사', '아', '자', '차', '카', '타', '파', '하',
+];
+
+/// (다른 형태, 호환용 자모나 음절)의 쌍을 모두 순회합니다.
+///
+/// 반각 자모(U+FFA1–U+FFDC), 괄호 친 자모와 음절(U+3200–U+321C),
+/// 원문자 자모와 음절(U+3260–U+327E) 중 한 글자로 나타낼 수 있는 것들을 포함합니다.
+fn compatibility_form_pairs() -> impl Iterator<Item = (char, char)> {
+    let shift = |from: u32, to: u32, count: u32| {
+        (0..count).map(move |index| (char::from_u32(from + index).unwrap(), char::from_u32(to + index).unwrap()))
+    };
+    let enclosed = |start: u32, chars: &'static [char]| {
+        chars.iter().enumerate().map(move |(index, chr)| (char::from_u32(start + index as u32).unwrap(), *chr))
+    };
+
+    // 반각 자음은 호환용 자모와 같은 순서이며 모음은 중간중간 빈 칸이 있음
+    shift(0xFFA1, 0x3131, 30)
+        .chain(shift(0xFFC2, 0x314F, 6))
+        .chain(shift(0xFFCA, 0x3155, 6))
+        .chain(shift(0xFFD2, 0x315B, 6))
+        .chain(shift(0xFFDA, 0x3161, 3))
+        .chain(enclosed(0x3200, &ENCLOSED_CONSONANTS))
+        .chain(enclosed(0x320E, &ENCLOSED_SYLLABLES))
+        .chain([('㈜', '주')])
+        .chain(enclosed(0x3260, &ENCLOSED_CONSONANTS))
+        .chain(enclosed(0x326E, &ENCLOSED_SYLLABLES))
+        .chain([('㉾', '우')])
+}
+
+/// 반각 자모나 괄호, 원으로 둘러싸인 자모와 음절을 호환용 자모나 완성형 음절로 바꿉니다.
+/// 그 외의 문자는 그대로 반환합니다.
+///
+/// ```rust
+/// use korean_regex::hangul::normalize_form;
+///
+/// assert_eq!('ㄱ', normalize_form('\u{FFA1}'));
+/// assert_eq!('ㄱ', normalize_form('㉠'));
+/// assert_eq!('가', normalize_form('㈎'));
+/// assert_eq!('각', normalize_form('각'));
+/// ```
+pub fn normalize_form(chr: char) -> char {
+    compatibility_form_pairs()
+        .find(|(form, _)| *form == chr)
+        .map_or(chr, |(_, standard)| standard)
+}
+
+/// 호환용 자모나 완성형 음절의 다른 형태들을 순회합니다.
+pub(crate) fn compatibility_forms(standard: char) -> impl Iterator<Item = char> {
+    compatibility_form_pairs()
+        .filter(move |(_, chr)| *chr == standard)
+        .map(|(form, _)| form)
+}
+
+/// 다른 형태가 있는 모든 호환용 자모와 음절을 순회합니다.
+pub(crate) fn standard_forms() -> impl Iterator<Item = char> {
+    let mut standards: Vec<char> = compatibility_form_pairs().map(|(_, standard)| standard).collect();
+    standards.sort_unstable();
+    standards.dedup();
+    standards.into_iter()
+}
+
+/// 초성, 중성, 종성 enum이 공통으로 가지는 변환과 순회를 구현합니다.
+///
+/// `$table`은 순서에 따른 호환용 자모 표를 반환하며, 종성의 경우 종성이 없음을 나타내는 `'0'`은 제외합니다.
+macro_rules! impl_jamo {
+    ($name:ident, $kind:literal, |$order:ident| $table:expr) => {
+        impl $name {
+            fn table($order: Order) -> &'static [char] {
+                $table
+            }
+
+            #[doc = concat!("호환용 자모를 ", $kind, "으로 바꿉니다. ", $kind, "이 될 수 없는 문자라면 `None`입니다.")]
+            pub fn from_char(chr: char) -> Option<$name> {
+                $name::table(Order::Default)
+                    .iter()
+                    .position(|&jamo| jamo == chr)
+                    .map(|index| $name::ALL[index])
+            }
+
+            /// 호환용 자모로 바꿉니다.
+            pub fn to_char(self) -> char {
+                $name::table(Order::Default)[$name::ALL.iter().position(|&jamo| jamo == self).unwrap()]
+            }
+
+            #[doc = concat!("주어진 순서에서 이 ", $kind, "이 몇 번째인지를 0부터 세어 반환합니다.")]
+            ///
+            /// `Order::OldHangul`에서는 옛한글 자모가 현대 한글 자모 뒤에 오기에 `Order::Default`와 같습니다.
+            pub fn index(self, order: Order) -> usize {
+                let chr = self.to_char();
+                $name::table(order).iter().position(|&jamo| jamo == chr).unwrap()
+            }
+
+            #[doc = concat!("모든 ", $kind, "을 주어진 순서대로 순회합니다.")]
+            ///
+            /// `Order::OldHangul`의 옛한글 자모는 이 enum에 포함되지 않기에 건너뜁니다.
+            pub fn iter(order: Order) -> impl Iterator<Item = $name> {
+                $name::table(order).iter().filter_map(|&chr| $name::from_char(chr))
+            }
+        }
+
+        impl From<$name> for char {
+            fn from(jamo: $name) -> Self {
+                jamo.to_char()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.to_char())
+            }
+        }
+    };
+}
+
+/// 초성입니다. variant는 `Order::Default`의 순서대로 정의되어 있습니다.
+///
+/// ```rust
+/// use korean_regex::hangul::Chosung;
+/// use korean_regex::Order;
+///
+/// assert_eq!(Some(Chosung::SsangGiyeok), Chosung::from_char('ㄲ'));
+/// assert_eq!(1, Chosung::SsangGiyeok.index(Order::Default));
+/// assert_eq!(14, Chosung::SsangGiyeok.index(Order::RegularFirst));
+/// assert_eq!(None, Chosung::from_char('ㄳ'));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Chosung {
+    /// ㄱ
+    Giyeok,
+    /// ㄲ
+    SsangGiyeok,
+    /// ㄴ
+    Nieun,
+    /// ㄷ
+    Digeut,
+    /// ㄸ
+    SsangDigeut,
+    /// ㄹ
+    Rieul,
+    /// ㅁ
+    Mieum,
+    /// ㅂ
+    Bieup,
+    /// ㅃ
+    SsangBieup,
+    /// ㅅ
+    Siot,
+    /// ㅆ
+    SsangSiot,
+    /// ㅇ
+    Ieung,
+    /// ㅈ
+    Jieut,
+    /// ㅉ
+    SsangJieut,
+    /// ㅊ
+    Chieut,
+    /// ㅋ
+    Kieuk,
+    /// ㅌ
+    Tieut,
+    /// ㅍ
+    Pieup,
+    /// ㅎ
+    Hieut,
+}
+
+impl Chosung {
+    /// 모든 초성입니다. `Order::Default`의 순서를 따릅니다.
+    pub const ALL: [Chosung; 19] = [
+        Chosung::Giyeok, Chosung::SsangGiyeok, Chosung::Nieun, Chosung::Digeut, Chosung::SsangDigeut,
+        Chosung::Rieul, Chosung::Mieum, Chosung::Bieup, Chosung::SsangBieup, Chosung::Siot,
+        Chosung::SsangSiot, Chosung::Ieung, Chosung::Jieut, Chosung::SsangJieut, Chosung::Chieut,
+        Chosung::Kieuk, Chosung::Tieut, Chosung::Pieup, Chosung::Hieut,
+    ];
+}
+
+impl_jamo!(Chosung, "초성", |order| order.order().0);
+
+/// 중성입니다. variant는 `Order::Default`의 순서대로 정의되어 있습니다.
+///
+/// ```rust
+/// use korean_regex::hangul::Jungsung;
+/// use korean_regex::Order;
+///
+/// let jungsungs: String = Jungsung::iter(Order::RegularFirst).take(5).map(char::from).collect();
+/// assert_eq!("ㅏㅑㅓㅕㅗ", jungsungs);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Jungsung {
+    /// ㅏ
+    A,
+    /// ㅐ
+    Ae,
+    /// ㅑ
+    Ya,
+    /// ㅒ
+    Yae,
+    /// ㅓ
+    Eo,
+    /// ㅔ
+    E,
+    /// ㅕ
+    Yeo,
+    /// ㅖ
+    Ye,
+    /// ㅗ
+    O,
+    /// ㅘ
+    Wa,
+    /// ㅙ
+    Wae,
+    /// ㅚ
+    Oe,
+    /// ㅛ
+    Yo,
+    /// ㅜ
+    U,
+    /// ㅝ
+    Wo,
+    /// ㅞ
+    We,
+    /// ㅟ
+    Wi,
+    /// ㅠ
+    Yu,
+    /// ㅡ
+    Eu,
+    /// ㅢ
+    Ui,
+    /// ㅣ
+    I,
+}
+
+impl Jungsung {
+    /// 모든 중성입니다. `Order::Default`의 순서를 따릅니다.
+    pub const ALL: [Jungsung; 21] = [
+        Jungsung::A, Jungsung::Ae, Jungsung::Ya, Jungsung::Yae, Jungsung::Eo, Jungsung::E,
+        Jungsung::Yeo, Jungsung::Ye, Jungsung::O, Jungsung::Wa, Jungsung::Wae, Jungsung::Oe,
+        Jungsung::Yo, Jungsung::U, Jungsung::Wo, Jungsung::We, Jungsung::Wi, Jungsung::Yu,
+        Jungsung::Eu, Jungsung::Ui, Jungsung::I,
+    ];
+}
+
+impl_jamo!(Jungsung, "중성", |order| order.order().1);
+
+/// 종성입니다. variant는 `Order::Default`의 순서대로 정의되어 있습니다.
+///
+/// 종성이 없는 경우는 이 enum에 포함되지 않으며 `Option<Jongsung>`의 `None`으로 나타냅니다.
+/// 따라서 [`Jongsung::index`]는 종성이 없는 경우를 세지 않습니다.
+///
+/// ```rust
+/// use korean_regex::hangul::Jongsung;
+/// use korean_regex::Order;
+///
+/// assert_eq!(Some(Jongsung::RieulGiyeok), Jongsung::from_char('ㄺ'));
+/// assert_eq!(None, Jongsung::from_char('0'));
+/// assert_eq!(0, Jongsung::Giyeok.index(Order::Default));
+/// assert_eq!(27, Jongsung::iter(Order::Default).count());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Jongsung {
+    /// ㄱ
+    Giyeok,
+    /// ㄲ
+    SsangGiyeok,
+    /// ㄳ
+    GiyeokSiot,
+    /// ㄴ
+    Nieun,
+    /// ㄵ
+    NieunJieut,
+    /// ㄶ
+    NieunHieut,
+    /// ㄷ
+    Digeut,
+    /// ㄹ
+    Rieul,
+    /// ㄺ
+    RieulGiyeok,
+    /// ㄻ
+    RieulMieum,
+    /// ㄼ
+    RieulBieup,
+    /// ㄽ
+    RieulSiot,
+    /// ㄾ
+    RieulTieut,
+    /// ㄿ
+    RieulPieup,
+    /// ㅀ
+    RieulHieut,
+    /// ㅁ
+    Mieum,
+    /// ㅂ
+    Bieup,
+    /// ㅄ
+    BieupSiot,
+    /// ㅅ
+    Siot,
+    /// ㅆ
+    SsangSiot,
+    /// ㅇ
+    Ieung,
+    /// ㅈ
+    Jieut,
+    /// ㅊ
+    Chieut,
+    /// ㅋ
+    Kieuk,
+    /// ㅌ
+    Tieut,
+    /// ㅍ
+    Pieup,
+    /// ㅎ
+    Hieut,
+}
+
+impl Jongsung {
+    /// 모든 종성입니다. `Order::Default`의 순서를 따릅니다.
+    pub const ALL: [Jongsung; 27] = [
+        Jongsung::Giyeok, Jongsung::SsangGiyeok, Jongsung::GiyeokSiot, Jongsung::Nieun,
+        Jongsung::NieunJieut, Jongsung::NieunHieut, Jongsung::Digeut, Jongsung::Rieul,
+        Jongsung::RieulGiyeok, Jongsung::RieulMieum, Jongsung::RieulBieup, Jongsung::RieulSiot,
+        Jongsung::RieulTieut, Jongsung::RieulPieup, Jongsung::RieulHieut, Jongsung::Mieum,
+        Jongsung::Bieup, Jongsung::BieupSiot, Jongsung::Siot, Jongsung::SsangSiot, Jongsung::Ieung,
+        Jongsung::Jieut, Jongsung::Chieut, Jongsung::Kieuk, Jongsung::Tieut, Jongsung::Pieup,
+        Jongsung::Hieut,
+    ];
+}
+
+impl_jamo!(Jongsung, "종성", |order| &order.order().2[1..]);
+
+/// 완성형 한글 음절(`가`부터 `힣`까지) 하나를 나타냅니다.
+///
+/// 초성, 중성, 종성은 호환용 자모(`ㄱ`, `ㅏ` 등)로 다루며, 종성이 없는 경우는 `None`입니다.
+/// 각 음소의 위치는 `Order::Default`의 순서를 따릅니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Syllable(char);
+
+impl Syllable {
+    /// 문자가 완성형 한글 음절이라면 Syllable을 반환합니다.
+    ///
+    /// ```rust
+    /// use korean_regex::hangul::Syllable;
+    ///
+    /// assert!(Syllable::from_char('한').is_some());
+    /// assert!(Syllable::from_char('ㅎ').is_none());
+    /// assert!(Syllable::from_char('a').is_none());
+    /// ```
+    pub fn from_char(chr: char) -> Option<Syllable> {
+        (SYLLABLE_START..SYLLABLE_START + SYLLABLE_COUNT)
+            .contains(&(chr as u32))
+            .then_some(Syllable(chr))
+    }
+
+    /// 초성, 중성, 종성을 조합해 음절을 만듭니다.
+    ///
+    /// 해당 자리에 올 수 없는 음소가 있다면 InvalidPhonemeError를 냅니다.
+    /// 음소는 패턴에서 바로 오지 않기에 오류의 위치는 빈 범위(`0..0`)입니다.
+    ///
+    /// ```rust
+    /// use korean_regex::hangul::Syllable;
+    ///
+    /// assert_eq!('둳', Syllable::compose('ㄷ', 'ㅝ', Some('ㄷ')).unwrap().to_char());
+    /// assert_eq!('둬', Syllable::compose('ㄷ', 'ㅝ', None).unwrap().to_char());
+    /// assert!(Syllable::compose('ㄷ', 'ㅝ', Some('ㄸ')).is_err());
+    /// ```
+    pub fn compose(chosung: char, jungsung: char, jongsung: Option<char>) -> Result<Syllable, KoreanRegexError> {
+        // '0'은 표 안에서 종성이 없음을 나타내는 값이기에 종성으로 받지 않음
+        if jongsung == Some('0') {
+            return Err(KoreanRegexError::InvalidPhonemeError(
+                "0 is not valid phoneme.".to_string(),
+                '0',
+                Some(SlotKind::Jongsung),
+                0..0,
+            ));
+        }
+        convert_phonemes_to_syllable(chosung, jungsung, jongsung, Order::Default.order()).map(Syllable)
+    }
+
+    /// 타입이 정해진 초성, 중성, 종성을 조합해 음절을 만듭니다. 이 조합은 항상 성공합니다.
+    ///
+    /// ```rust
+    /// use korean_regex::hangul::{Chosung, Jongsung, Jungsung, Syllable};
+    ///
+    /// let syllable = Syllable::from_jamo(Chosung::Digeut, Jungsung::Wo, Some(Jongsung::Digeut));
+    /// assert_eq!('둳', syllable.to_char());
+    /// assert_eq!((Chosung::Digeut, Jungsung::Wo, Some(Jongsung::Digeut)), syllable.jamo());
+    /// ```
+    pub fn from_jamo(chosung: Chosung, jungsung: Jungsung, jongsung: Option<Jongsung>) -> Syllable {
+        let jongsung = jongsung.map_or(0, |jongsung| jongsung.index(Order::Default) + 1);
+        let code = SYLLABLE_START as usize
+            + 588 * chosung.index(Order::Default)
+            + 28 * jungsung.index(Order::Default)
+            + jongsung;
+        Syllable(char::from_u32(code as u32).unwrap())
+    }
+
+    /// 음절을 타입이 정해진 (초성, 중성, 종성)으로 분해합니다.
+    pub fn jamo(self) -> (Chosung, Jungsung, Option<Jongsung>) {
+        (
+            Chosung::ALL[self.offset() / 588],
+            Jungsung::ALL[self.offset() % 588 / 28],
+            match self.offset() % 28 {
+                0 => None,
+                index => Some(Jongsung::ALL[index - 1]),
+            },
+        )
+    }
+
+    /// 음절을 문자로 반환합니다.
+    pub fn to_char(self) -> char {
+        self.0
+    }
+
+    /// 음절을 (초성, 중성, 종성)으로 분해합니다.
+    pub fn decompose(self) -> (char, char, Option<char>) {
+        (self.chosung(), self.jungsung(), self.jongsung())
+    }
+
+    fn offset(self) -> usize {
+        (self.0 as u32 - SYLLABLE_START) as usize
+    }
+
+    /// 초성을 반환합니다.
+    pub fn chosung(self) -> char {
+        Order::Default.order().0[self.offset() / 588]
+    }
+
+    /// 중성을 반환합니다.
+    pub fn jungsung(self) -> char {
+        Order::Default.order().1[self.offset() % 588 / 28]
+    }
+
+    /// 종성을 반환합니다. 종성이 없다면 `None`입니다.
+    pub fn jongsung(self) -> Option<char> {
+        match self.offset() % 28 {
+            0 => None,
+            index => Some(Order::Default.order().2[index]),
+        }
+    }
+
+    /// 초성을 바꾼 음절을 반환합니다.
+    pub fn with_chosung(self, chosung: char) -> Result<Syllable, KoreanRegexError> {
+        Syllable::compose(chosung, self.jungsung(), self.jongsung())
+    }
+
+    /// 중성을 바꾼 음절을 반환합니다.
+    ///
+    /// ```rust
+    /// use korean_regex::hangul::Syllable;
+    ///
+    /// let syllable = Syllable::from_char('강').unwrap();
+    /// assert_eq!('궁', syllable.with_jungsung('ㅜ').unwrap().to_char());
+    /// ```
+    pub fn with_jungsung(self, jungsung: char) -> Result<Syllable, KoreanRegexError> {
+        Syllable::compose(self.chosung(), jungsung, self.jongsung())
+    }
+
+    /// 종성을 바꾼 음절을 반환합니다. `None`이라면 종성을 없앱니다.
+    ///
+    /// ```rust
+    /// use korean_regex::hangul::Syllable;
+    ///
+    /// let syllable = Syllable::from_char('닭').unwrap();
+    /// assert_eq!('다', syllable.with_jongsung(None).unwrap().to_char());
+    /// ```
+    pub fn with_jongsung(self, jongsung: Option<char>) -> Result<Syllable, KoreanRegexError> {
+        Syllable::compose(self.chosung(), self.jungsung(), jongsung)
+    }
+}
+
+impl From<Syllable> for char {
+    fn from(syllable: Syllable) -> Self {
+        syllable.0
+    }
+}
+
+impl fmt::Display for Syllable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_syllable_round_trip() {
+        for chr in (SYLLABLE_START..SYLLABLE_START + SYLLABLE_COUNT).filter_map(char::from_u32) {
+            let syllable = Syllable::from_char(chr).unwrap();
+            let (chosung, jungsung, jongsung) = syllable.decompose();
+            assert_eq!(syllable, Syllable::compose(chosung, jungsung, jongsung).unwrap());
+        }
+        assert!(Syllable::from_char('\u{ABFF}').is_none());
+        assert!(Syllable::from_char('\u{D7A4}').is_none());
+    }
+
+    #[test]
+    fn test_jamo() {
+        for order in [Order::Default, Order::RegularFirst] {
+            let (chosungs, jungsungs, jongsungs) = order.order();
+            assert_eq!(chosungs, Chosung::iter(order).map(char::from).collect::<Vec<_>>());
+            assert_eq!(jungsungs, Jungsung::iter(order).map(char::from).collect::<Vec<_>>());
+            assert_eq!(&jongsungs[1..], Jongsung::iter(order).map(char::from).collect::<Vec<_>>());
+            for (index, chosung) in Chosung::iter(order).enumerate() {
+                assert_eq!(index, chosung.index(order));
+            }
+        }
+        for (index, jongsung) in Jongsung::ALL.into_iter().enumerate() {
+            assert_eq!(index, jongsung.index(Order::Default));
+            assert_eq!(Some(jongsung), Jongsung::from_char(jongsung.to_char()));
+        }
+        assert_eq!(19, Chosung::iter(Order::OldHangul).count());
+        assert_eq!(27, Jongsung::iter(Order::OldHangul).count());
+        assert_eq!(None, Jungsung::from_char('ㄱ'));
+        assert_eq!("ㅢ", Jungsung::Ui.to_string());
+
+        for chr in (SYLLABLE_START..SYLLABLE_START + SYLLABLE_COUNT).filter_map(char::from_u32) {
+            let syllable = Syllable::from_char(chr).unwrap();
+            let (chosung, jungsung, jongsung) = syllable.jamo();
+            assert_eq!(syllable, Syllable::from_jamo(chosung, jungsung, jongsung));
+            assert_eq!(syllable.jongsung(), jongsung.map(char::from));
+        }
+    }
+
+    #[test]
+    fn test_compound_jamo() {
+        for (compound, (first, second)) in COMPOUND_JAMO {
+            assert_eq!(Some(compound), compose_jamo(first, second));
+            assert_eq!(Some((first, second)), decompose_jamo(compound));
+        }
+        // 두 자모로 이루어진 모든 초성, 중성, 종성이 표에 있어야 함
+        let (chosungs, jungsungs, jongsungs) = Order::Default.order();
+        let compounds: Vec<char> = "ㄲㄸㅃㅆㅉㄳㄵㄶㄺㄻㄼㄽㄾㄿㅀㅄㅘㅙㅚㅝㅞㅟㅢ".chars().collect();
+        for chr in chosungs.iter().chain(jungsungs).chain(jongsungs) {
+            assert_eq!(compounds.contains(chr), decompose_jamo(*chr).is_some(), "{chr}");
+        }
+    }
+
+    #[test]
+    fn test_compatibility_forms() {
+        assert_eq!('ㅀ', normalize_form('\u{FFB0}'));
+        assert_eq!('ㅏ', normalize_form('\u{FFC2}'));
+        assert_eq!('ㅕ', normalize_form('\u{FFCA}'));
+        assert_eq!('ㅛ', normalize_form('\u{FFD2}'));
+        assert_eq!('ㅣ', normalize_form('\u{FFDC}'));
+        assert_eq!('ㅎ', normalize_form('㉭'));
+        assert_eq!('하', normalize_form('㉻'));
+        assert_eq!('주', normalize_form('㈜'));
+        assert_eq!('\u{FFA0}', normalize_form('\u{FFA0}'));
+
+        assert_eq!(vec!['\u{FFA1}', '㈀', '㉠'], compatibility_forms('ㄱ').collect::<Vec<_>>());
+        assert_eq!(
+            51 + 14 + 2,
+            standard_forms().count()
+        );
+    }
+
+    #[test]
+    fn test_conjoining_jamo() {
+        let conjoining = |chr: char| -> String {
+            let (chosung, jungsung, jongsung) = Syllable::from_char(chr).unwrap().decompose();
+            [
+                conjoining_jamo(chosung, SlotKind::Chosung),
+                conjoining_jamo(jungsung, SlotKind::Jungsung),
+                jongsung.and_then(|jongsung| conjoining_jamo(jongsung, SlotKind::Jongsung)),
+            ]
+            .into_iter()
+            .flatten()
+            .collect()
+        };
+        assert_eq!("\u{1100}\u{1161}\u{11A8}", conjoining('각'));
+        assert_eq!("\u{1112}\u{1175}\u{11C2}", conjoining('힣'));
+        assert_eq!("\u{1101}\u{1174}", conjoining('끠'));
+        assert_eq!(Some('\u{1140}'), conjoining_jamo('ㅿ', SlotKind::Chosung));
+        assert_eq!(Some('\u{11EB}'), conjoining_jamo('ㅿ', SlotKind::Jongsung));
+        assert_eq!(None, conjoining_jamo('ㅿ', SlotKind::Jungsung));
+        assert_eq!(Some('\u{11A1}'), conjoining_jamo('ㆎ', SlotKind::Jungsung));
+        assert_eq!(None, conjoining_jamo('ㆍ', SlotKind::Chosung));
+    }
+
+    #[test]
+    fn test_syllable() {
+        let syllable = Syllable::from_char('힣').unwrap();
+        assert_eq!(('ㅎ', 'ㅣ', Some('ㅎ')), syllable.decompose());
+        assert_eq!('힣', char::from(syllable));
+        assert_eq!("힣", syllable.to_string());
+        assert_eq!('깋', syllable.with_chosung('ㄱ').unwrap().to_char());
+
+        match syllable.with_jongsung(Some('0')).unwrap_err() {
+            KoreanRegexError::InvalidPhonemeError(_, phoneme, kind, _) => {
+                assert_eq!('0', phoneme);
+                assert_eq!(Some(SlotKind::Jongsung), kind);
+            }
+            _ => panic!("Should raise InvalidPhonemeError"),
+        }
+        match syllable.with_chosung('ㄳ').unwrap_err() {
+            KoreanRegexError::InvalidPhonemeError(_, phoneme, kind, _) => {
+                assert_eq!('ㄳ', phoneme);
+                assert_eq!(Some(SlotKind::Chosung), kind);
+            }
+            _ => panic!("Should raise InvalidPhonemeError"),
+        }
+    }
+}
@@ -0,0 +1,238 @@
+//! 한/영 전환을 하지 않고 입력한 로마자 키 입력을 한글로 바꾸는 도구들입니다.
+//!
+//! ```rust
+//! use korean_regex::keyboard::{self, Layout};
+//! use korean_regex::*;
+//!
+//! assert_eq!("한글", keyboard::transliterate("gksrmf", Layout::Dubeolsik));
+//!
+//! let pattern = compile(&keyboard::query_pattern("gksr", Layout::Dubeolsik), Order::Default).unwrap();
+//! assert!(pattern.is_match("한국어"));
+//! assert!(pattern.is_match("한글"));
+//! ```
+
+use crate::hangul::{compose_jamo, decompose_jamo, Jongsung, Jungsung, Syllable};
+use crate::prefix_pattern;
+
+/// 키보드 자판 배열입니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Layout {
+    /// 표준 두벌식 자판입니다.
+    ///
+    /// 된소리와 `ㅒ`, `ㅖ`를 제외한 대문자는 소문자와 같은 자모로 취급합니다.
+    #[default]
+    Dubeolsik,
+}
+
+impl Layout {
+    /// 키에 해당하는 호환용 자모를 반환합니다. 자모에 해당하지 않는 키라면 `None`입니다.
+    ///
+    /// ```rust
+    /// use korean_regex::keyboard::Layout;
+    ///
+    /// assert_eq!(Some('ㄱ'), Layout::Dubeolsik.jamo('r'));
+    /// assert_eq!(Some('ㄲ'), Layout::Dubeolsik.jamo('R'));
+    /// assert_eq!(Some('ㅏ'), Layout::Dubeolsik.jamo('K'));
+    /// assert_eq!(None, Layout::Dubeolsik.jamo('1'));
+    /// ```
+    pub fn jamo(self, key: char) -> Option<char> {
+        match self {
+            Layout::Dubeolsik => dubeolsik_jamo(key),
+        }
+    }
+}
+
+fn dubeolsik_jamo(key: char) -> Option<char> {
+    let jamo = match key {
+        'Q' => 'ㅃ',
+        'W' => 'ㅉ',
+        'E' => 'ㄸ',
+        'R' => 'ㄲ',
+        'T' => 'ㅆ',
+        'O' => 'ㅒ',
+        'P' => 'ㅖ',
+        'q' => 'ㅂ',
+        'w' => 'ㅈ',
+        'e' => 'ㄷ',
+        'r' => 'ㄱ',
+        't' => 'ㅅ',
+        'y' => 'ㅛ',
+        'u' => 'ㅕ',
+        'i' => 'ㅑ',
+        'o' => 'ㅐ',
+        'p' => 'ㅔ',
+        'a' => 'ㅁ',
+        's' => 'ㄴ',
+        'd' => 'ㅇ',
+        'f' => 'ㄹ',
+        'g' => 'ㅎ',
+        'h' => 'ㅗ',
+        'j' => 'ㅓ',
+        'k' => 'ㅏ',
+        'l' => 'ㅣ',
+        'z' => 'ㅋ',
+        'x' => 'ㅌ',
+        'c' => 'ㅊ',
+        'v' => 'ㅍ',
+        'b' => 'ㅠ',
+        'n' => 'ㅜ',
+        'm' => 'ㅡ',
+        _ if key.is_ascii_uppercase() => return dubeolsik_jamo(key.to_ascii_lowercase()),
+        _ => return None,
+    };
+    Some(jamo)
+}
+
+/// 입력기처럼 자모를 하나씩 받아 음절을 조합합니다.
+#[derive(Default)]
+struct Automaton {
+    chosung: Option<char>,
+    jungsung: Option<char>,
+    jongsung: Option<char>,
+    output: String,
+}
+
+impl Automaton {
+    fn push(&mut self, jamo: char) {
+        if Jungsung::from_char(jamo).is_some() {
+            self.push_jungsung(jamo);
+        } else {
+            self.push_consonant(jamo);
+        }
+    }
+
+    fn push_consonant(&mut self, consonant: char) {
+        match (self.chosung, self.jungsung, self.jongsung) {
+            (Some(_), Some(_), None) if Jongsung::from_char(consonant).is_some() => {
+                self.jongsung = Some(consonant);
+                return;
+            }
+            (Some(_), Some(_), Some(jongsung)) => {
+                // 된소리는 받침에서 두 번 눌러 만들 수 없습니다.
+                if let Some(compound) = compose_jamo(jongsung, consonant)
+                    .filter(|&compound| jongsung != consonant && Jongsung::from_char(compound).is_some())
+                {
+                    self.jongsung = Some(compound);
+                    return;
+                }
+            }
+            _ => {}
+        }
+        self.flush();
+        self.chosung = Some(consonant);
+    }
+
+    fn push_jungsung(&mut self, jungsung: char) {
+        match (self.jungsung, self.jongsung) {
+            (None, _) if self.chosung.is_some() => {
+                self.jungsung = Some(jungsung);
+                return;
+            }
+            (Some(previous), None) => {
+                if let Some(compound) = compose_jamo(previous, jungsung) {
+                    self.jungsung = Some(compound);
+                    return;
+                }
+            }
+            (Some(_), Some(jongsung)) => {
+                // 받침이 다음 음절의 초성으로 넘어갑니다. 겹받침은 뒤의 자음만 넘어갑니다.
+                let (remaining, moved) = match decompose_jamo(jongsung) {
+                    Some((first, second)) if first != second => (Some(first), second),
+                    _ => (None, jongsung),
+                };
+                self.jongsung = remaining;
+                self.flush();
+                self.chosung = Some(moved);
+                self.jungsung = Some(jungsung);
+                return;
+            }
+            _ => {}
+        }
+        self.flush();
+        self.jungsung = Some(jungsung);
+    }
+
+    fn flush(&mut self) {
+        match (self.chosung.take(), self.jungsung.take(), self.jongsung.take()) {
+            (Some(chosung), Some(jungsung), jongsung) => {
+                let syllable = Syllable::compose(chosung, jungsung, jongsung).unwrap();
+                self.output.push(syllable.to_char());
+            }
+            (chosung, jungsung, _) => self.output.extend(chosung.into_iter().chain(jungsung)),
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.flush();
+        self.output
+    }
+}
+
+/// 주어진 자판으로 입력한 키들을 입력기처럼 조합하여 한글로 바꿉니다.
+///
+/// 자모에 해당하지 않는 문자는 그대로 남으며, 조합 중인 음절을 끊습니다.
+/// 음절이 될 수 없는 자모는 호환용 자모로 남습니다.
+///
+/// ```rust
+/// use korean_regex::keyboard::{transliterate, Layout};
+///
+/// assert_eq!("한국어", transliterate("gksrnrdj", Layout::Dubeolsik));
+/// assert_eq!("닭이 왔다", transliterate("ekfrdl dhkTek", Layout::Dubeolsik));
+/// assert_eq!("ㅋㅋ 1등", transliterate("zz 1emd", Layout::Dubeolsik));
+/// assert_eq!("ㄱ2ㅏ", transliterate("r2k", Layout::Dubeolsik));
+/// ```
+pub fn transliterate(keys: &str, layout: Layout) -> String {
+    let mut automaton = Automaton::default();
+    for key in keys.chars() {
+        match layout.jamo(key) {
+            Some(jamo) => automaton.push(jamo),
+            None => {
+                automaton.flush();
+                automaton.output.push(key);
+            }
+        }
+    }
+    automaton.finish()
+}
+
+/// 입력 중인 키들을 한글로 바꾼 뒤 [`prefix_pattern`]으로 한국어 regex 패턴을 만듭니다.
+///
+/// 마지막 음절은 아직 입력 중일 수 있기에 앞으로 될 수 있는 음절들에 모두 match합니다.
+///
+/// ```rust
+/// use korean_regex::keyboard::{query_pattern, Layout};
+/// use korean_regex::*;
+///
+/// assert_eq!("한[ㄱ:ㅡㅢ:]", query_pattern("gksrm", Layout::Dubeolsik));
+///
+/// let pattern = compile(&query_pattern("gksrm", Layout::Dubeolsik), Order::Default).unwrap();
+/// assert!(pattern.is_match("한글"));
+/// assert!(!pattern.is_match("한국"));
+/// ```
+pub fn query_pattern(keys: &str, layout: Layout) -> String {
+    prefix_pattern(&transliterate(keys, layout))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_transliterate() {
+        let layout = Layout::Dubeolsik;
+        assert_eq!("", transliterate("", layout));
+        // 이중 모음과 겹받침
+        assert_eq!("괜찮아", transliterate("rhoscksgdk", layout));
+        assert_eq!("읽어", transliterate("dlfrdj", layout));
+        // 된소리 받침은 통째로 넘어감
+        assert_eq!("있어", transliterate("dlTdj", layout));
+        // 받침에서는 된소리를 두 번 눌러 만들 수 없음
+        assert_eq!("갓사", transliterate("rkttk", layout));
+        // 받침이 될 수 없는 자음
+        assert_eq!("가ㄸ", transliterate("rkE", layout));
+        // 초성 없는 모음
+        assert_eq!("ㅏㅘ", transliterate("khk", layout));
+        assert_eq!("ㄱㄴ", transliterate("rs", layout));
+    }
+}
@@ -111,6 +111,19 @@
 //! let order = Order::Default;
 //! assert_eq!("[곿괇궧궯뽟뽧쀇쀏]", compile("[ㄱ(ㅂㅂ):(ㅗㅏ)(ㅜㅔ):(ㄹㅂ)(ㄱㅅ)]", order).unwrap().to_string());
 //! ```
+//!
+//! 자주 쓰는 자모의 묶음은 `@경음`처럼 이름으로 쓸 수 있습니다. 이름이 붙은 집합은
+//! 다른 자모나 하이픈 범위와 섞어 쓸 수 있고 `^`로 뒤집을 수도 있으며, 그 자리에 올 수 있는 자모로만 풀립니다.
+//! 사용할 수 있는 집합은 [`hangul::NAMED_JAMO_SETS`]에 있습니다.
+//! 다만 `[@abc:]`처럼 자모도 알려진 집합도 없는 클래스는 정규표현식의 문자 클래스 그대로 남습니다.
+//!
+//! ```rust
+//! use korean_regex::*;
+//!
+//! let order = Order::Default;
+//! assert_eq!("[갂갔]", compile("[ㄱ:ㅏ:@경음]", order).unwrap().to_string());
+//! assert_eq!("[가나차카타파]", compile("[ㄱㄴ@격음:ㅏ]", order).unwrap().to_string());
+//! ```
 //! 
 //! 이 고유 문법이 적용되는 범위를 넘어서면 기본 정규 표현식과 같이 섞어 사용할 수 있습니다.
 //! 
@@ -142,6 +155,81 @@
 //! assert_eq!(vec!["초성", "중성", "종성의", "사용하"], result)
 //! ```
 //!
+//! ## Jamo backreference
+//!
+//! 슬롯의 한 자리 끝에 `<이름>`을 붙이면 그 자리에 온 자모가 이름에 묶이고,
+//! 뒤의 슬롯에서 같은 자리를 `\k<이름>`으로 채우면 묶인 자모와 같은 자모만 match합니다.
+//!
+//! 정규표현식 크레이트는 역참조를 지원하지 않기에, 묶은 슬롯부터 마지막으로 요구하는 슬롯까지가
+//! 묶일 수 있는 자모마다 한 번씩 나열됩니다. 따라서 그 사이에는 캡처 그룹이나 그룹 바깥의 `|`가 올 수 없으며,
+//! 묶을 수 있는 자모가 많을수록 정규표현식이 커진다는 점을 주의해 주세요.
+//!
+//! ```rust
+//! use korean_regex::*;
+//!
+//! let order = Order::Default;
+//! assert_eq!("(?:[가-깋][기-깋]|[나-닣][니-닣])", compilestr(r"[ㄱㄴ<a>::][\k<a>:ㅣ:]", order).unwrap());
+//!
+//! // 초성이 같은 두 음절
+//! let pattern = compile(r"[<a>::][\k<a>::]", order).unwrap();
+//! assert!(pattern.is_match("싱숭생숭"));
+//! assert!(!pattern.is_match("가나다"));
+//! ```
+//!
+//! ## Slot captures
+//!
+//! [`KoreanRegexBuilder::build_captures`]로 컴파일하면 match마다 각 슬롯에 어떤 음절이 match되었는지 알 수 있습니다.
+//! 슬롯의 자리에 `(?<이름>...)`으로 이름을 붙이면 그 자리에 match된 자모를 이름으로 찾을 수 있습니다.
+//!
+//! ```rust
+//! use korean_regex::*;
+//!
+//! let regex = KoreanRegexBuilder::new("[(?<c>ㄱㄴ):ㅏㅣ:][ㄷ::]").build_captures().unwrap();
+//! let captures = regex.captures("오늘 날도").unwrap();
+//! assert_eq!(Some('ㄴ'), captures.name("c"));
+//! assert_eq!(Some('ㅗ'), captures.slots()[1].jamo(SlotKind::Jungsung));
+//! ```
+//!
+//! ## Chosung search
+//!
+//! `\c{...}` 안에 자음을 적으면 해당 자음이 초성인 모든 음절에 match합니다. 완성된 음절이나
+//! 다른 문자는 그대로 match하기에 `\c{ㅎ국}`은 `한국`, `항국`, `휴국` 등에 match합니다.
+//!
+//! ```rust
+//! use korean_regex::*;
+//!
+//! let order = Order::Default;
+//! assert_eq!("[하-힣]국", compilestr(r"\c{ㅎ국}", order).unwrap());
+//! let pattern = compile(r"\c{ㄱㄴ}", order).unwrap();
+//! assert!(pattern.is_match("고냥이"));
+//! ```
+//!
+//! ## Hangul escapes
+//!
+//! 대괄호 밖에서 `\가`는 모든 음절에, `\J`는 모든 호환용 자모에, `\H`는 그 둘 모두에 match합니다.
+//! 유니코드 속성의 `\P{...}`처럼 적은 `\P{가}`, `\P{J}`, `\P{H}`는 각각 그 외의 한 글자에 match합니다.
+//!
+//! ```rust
+//! use korean_regex::*;
+//!
+//! let order = Order::Default;
+//! assert_eq!("[가-힣]+ [^가-힣ㄱ-ㅣ]+", compilestr(r"\가+ \P{H}+", order).unwrap());
+//! let pattern = compile(r"\P{H}+\가", order).unwrap();
+//! assert_eq!("Rust로", pattern.find("Rust로 ㅋㅋ").unwrap().as_str());
+//! ```
+//!
+//! ## Keyboard input
+//!
+//! 한/영 전환을 하지 않고 입력한 `gksrmf`과 같은 키 입력은 [`keyboard`] 모듈로 한글로 바꿀 수 있습니다.
+//!
+//! ```rust
+//! use korean_regex::keyboard::{self, Layout};
+//! use korean_regex::*;
+//!
+//! let pattern = compile(&keyboard::query_pattern("gksrm", Layout::Dubeolsik), Order::Default).unwrap();
+//! assert!(pattern.is_match("한글"));
+//! ```
+//!
 //! ## Hyphen replacing
 //!
 //! 정규표현식의 `[]` 문법에는 연속되는 문자를 대체하는 `-` 문법이 있습니다.
@@ -155,9 +243,20 @@
 //! assert_eq!("[가-깋라-맇]", compile("[ㄱㄹ::]", order).unwrap().to_string());
 //! ```
 
+mod builder;
+mod cache;
+mod captures;
+mod error;
+pub mod hangul;
+pub mod keyboard;
+mod parser;
+mod reference;
 mod substitute;
 
-use regex::Regex;
+pub use builder::KoreanRegexBuilder;
+pub use cache::{clear_cache, set_cache_capacity};
+pub use captures::{KoreanCaptures, KoreanRegex, SlotMatch};
+pub use error::{ErrorKind, KoreanRegexError, KoreanRegexSetError};
 pub use substitute::substitute;
 
 type CompiledOrders<'a> = (&'a [char], &'a [char], &'a [char]);
@@ -186,28 +285,23 @@ const JONSGSUNGS_REGFIRST: [char; 28] = [
     'ㅎ', 'ㄲ', 'ㄳ', 'ㄵ', 'ㄶ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅄ', 'ㅆ'
 ];
 
-/// korean-regex에서 나올 수 있는 모든 오류를 모아놓은 enum입니다.
-#[derive(Debug)]
-pub enum KoreanRegexError {
-    /// 괄호로 묶인 문자를 합치는 것에 실패했을 때 나타나는 오류입니다.
-    /// 예를 들어 `(ㄱㅇ)`는 적절하지 않은 괄호 문법이기에 오류를 냅니다.
-    UnparenthesizingFailedError(String),
-    /// 하이픈이 맨 앞이나 맨 뒤에 나오거나 문자 인덱스가 잘못되었을 경우 발생합니다.
-    /// 예를 들어 `[-ㅅ::]`는 하이픈의 앞에 해당하는 문자가 없기에 오류를 냅니다.
-    /// 또한 `[ㅅ-ㄱ::]`은 하이픈의 앞의 문자가 뒤의 문자보다 인덱스가 더 크기에 오류가 납니다.
-    InvalidHyphenError(String),
-    /// 0은 종성이 위치에서는 올 수 있지만 초성이나 중성에서는 제한적이게만 가능합니다.
-    /// 예를 들어 `[0ㄱ:ㅏ0:ㄱ]`같이 초성이나 중성에는 0을 섞어서 쓸 수 없으며 오직
-    /// 0이거나 0이 아닌 문자들이거나 둘 중 하나만 가능합니다.
-    /// 또한 중성이 없는 한글은 없기에 `[ㄱ:0:ㄱ]`같은 패턴 또한 불가능하며
-    /// 초성이나 중성에 문자가 들어갈 수 있는 패턴은 오직 `[*:0:0]`이나 `[0:*:0]`뿐입니다.
-    /// 이 규칙을 어겼을 경우 이 오류가 납니다.
-    InvalidZeroPatternError(String),
-    /// 한글 음소가 아닌 글자가 왔을 경우 발생합니다. 예를 들어 `[d:ㅏ:ㄴ]`은 이 오류를 발생시킵니다.
-    InvalidPhonemeError(String, char),
-    /// compile 함수에서 regex 관련 오류가 일어났을 경우 사용됩니다.
-    RegexError(regex::Error),
-}
+const CHOSUNGS_OLD: [char; 45] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ',
+    'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ', 'ㅥ', 'ㅦ', 'ㅧ', 'ㅪ', 'ㅮ', 'ㅯ', 'ㅱ', 'ㅲ', 'ㅳ',
+    'ㅴ', 'ㅵ', 'ㅶ', 'ㅷ', 'ㅸ', 'ㅹ', 'ㅺ', 'ㅻ', 'ㅼ', 'ㅽ', 'ㅾ', 'ㅿ', 'ㆀ', 'ㆁ',
+    'ㆄ', 'ㆅ', 'ㆆ',
+];
+const JUNGSUNGS_OLD: [char; 29] = [
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ',
+    'ㅝ', 'ㅞ', 'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ', 'ㆇ', 'ㆈ', 'ㆉ', 'ㆊ', 'ㆋ', 'ㆌ', 'ㆍ',
+    'ㆎ',
+];
+const JONGSUNGS_OLD: [char; 56] = [
+    '0', 'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ',
+    'ㄿ', 'ㅀ', 'ㅁ', 'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+    'ㅥ', 'ㅦ', 'ㅧ', 'ㅨ', 'ㅩ', 'ㅪ', 'ㅫ', 'ㅬ', 'ㅭ', 'ㅮ', 'ㅯ', 'ㅰ', 'ㅱ', 'ㅳ',
+    'ㅵ', 'ㅶ', 'ㅸ', 'ㅺ', 'ㅼ', 'ㅽ', 'ㅾ', 'ㅿ', 'ㆀ', 'ㆁ', 'ㆂ', 'ㆃ', 'ㆄ', 'ㆆ',
+];
 
 /// 하이픈 구성 시 사용할 순서를 결정합니다.
 ///
@@ -243,7 +337,10 @@ pub enum KoreanRegexError {
 /// `[ㄲㄴ]`가 되고 `Order::RegularFirst`에서도 `[ㄲㄴ]`가 됩니다.
 ///
 /// 하이픈 사용 시 두 순서 중에서 어느 것이 자신의 필요에 맞는지 확인하고 사용하시면 됩니다.
-#[derive(Debug, Clone, Copy)]
+///
+/// 이후 버전에서 순서가 더 추가될 수 있기에 이 enum을 match할 때는 `_` 갈래가 필요합니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum Order {
     /// 기본 순서입니다.
     ///
@@ -252,6 +349,7 @@ pub enum Order {
     /// 중성: ㅏㅐㅑㅒㅓㅔㅕㅖㅗㅘㅙㅚㅛㅜㅝㅞㅟㅠㅡㅢㅣ
     /// 종성: 0ㄱㄲㄳㄴㄵㄶㄷㄹㄺㄻㄼㄽㄾㄿㅀㅁㅂㅄㅅㅆㅇㅈㅊㅋㅌㅍㅎ
     /// ```
+    #[default]
     Default,
     /// 정규 음운 선행 순서입니다.
     ///
@@ -261,16 +359,32 @@ pub enum Order {
     /// 종성: 0ㄱㄴㄷㄹㅁㅂㅅㅇㅈㅊㅋㅌㅍㅎㄲㄳㄵㄶㄺㄻㄼㄽㄾㄿㅀㅄㅆ
     /// ```
     RegularFirst,
-}
-
-impl Default for Order {
-    fn default() -> Self {
-        Self::Default
-    }
+    /// 옛한글 순서입니다.
+    ///
+    /// `Order::Default`의 현대 한글 자모 뒤에 `ㅿ`, `ㆁ`, `ㆆ`, `ㆍ`와 같은 옛한글 호환용 자모가
+    /// 유니코드 순서대로 이어집니다. 옛한글 자모는 각 자리에 올 수 있는 경우에만 포함됩니다.
+    ///
+    /// 옛한글 자모에는 완성형 음절이 없기에 옛한글 자모가 들어간 슬롯은 첫가끝 자모의 나열에 match하는
+    /// `(?:[...]|...)` 형태로 컴파일됩니다. 현대 한글만으로 이루어진 음절은 여전히 완성형 음절로 match합니다.
+    ///
+    /// ```rust
+    /// use korean_regex::*;
+    ///
+    /// let regex = compile("[ㅎ:ㆍ:ㄴ]", Order::OldHangul).unwrap();
+    /// assert_eq!("(?:[ᄒ][ᆞ][ᆫ])", regex.as_str());
+    /// assert!(regex.is_match("\u{1112}\u{119E}\u{11AB}"));
+    ///
+    /// let regex = compile("[ㄱ:ㅏㆍ]", Order::OldHangul).unwrap();
+    /// assert_eq!("(?:[가]|[ᄀ][ᅡᆞ])", regex.as_str());
+    /// ```
+    OldHangul,
 }
 
 impl Order {
     /// (초성, 중성, 종성(+0))으로 이루어진 튜플을 반환합니다.
+    ///
+    /// 종성의 첫 번째 값인 `'0'`은 종성이 없음을 나타냅니다.
+    /// 타입이 정해진 음소가 필요하다면 [`hangul::Chosung::iter`]와 같은 함수를 사용하세요.
     pub fn order(self) -> (&'static [char], &'static [char], &'static [char]) {
         match self {
             Order::Default => {
@@ -279,6 +393,32 @@ impl Order {
             Order::RegularFirst => {
                 (&CHOSUNGS_REGFIRST, &JUNGSUNGS_REGFIRST, &JONSGSUNGS_REGFIRST)
             }
+            Order::OldHangul => {
+                (&CHOSUNGS_OLD, &JUNGSUNGS_OLD, &JONGSUNGS_OLD)
+            }
+        }
+    }
+}
+
+/// 슬롯 안의 초성, 중성, 종성 자리를 나타냅니다.
+///
+/// `[ㄱ:ㅏ:ㄴ]`에서 `ㄱ`은 초성, `ㅏ`는 중성, `ㄴ`은 종성 자리에 있습니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SlotKind {
+    /// 초성 자리입니다.
+    Chosung,
+    /// 중성 자리입니다.
+    Jungsung,
+    /// 종성 자리입니다.
+    Jongsung,
+}
+
+impl SlotKind {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            SlotKind::Chosung => "chosung",
+            SlotKind::Jungsung => "jungsung",
+            SlotKind::Jongsung => "jongsung",
         }
     }
 }
@@ -301,6 +441,18 @@ impl Order {
 /// 만약 다른 정규표현식 크레이트를 이용하고 싶은 경우에
 /// compilestr을 이용해 다른 정규표현식 크레이트를 사용할 수 있습니다.
 ///
+/// 패턴은 정규표현식 문법에 따라 해석되기 때문에 이스케이프된 괄호(`\[ㄱ::]`),
+/// 다른 문자 클래스 안에 있는 괄호(`[a[ㄱ::]]`), `(?x)` 모드의 주석 안에 있는 괄호는
+/// 한국어 문법으로 취급되지 않으며 그대로 남습니다.
+///
+/// ```rust
+/// use korean_regex::*;
+///
+/// let order = Order::Default;
+/// assert_eq!(r"\[ㄱ:ㅏ:ㄱ][각]", compilestr(r"\[ㄱ:ㅏ:ㄱ][ㄱ:ㅏ:ㄱ]", order).unwrap());
+/// assert_eq!("[a[ㄱ:ㅏ:ㄱ]]", compilestr("[a[ㄱ:ㅏ:ㄱ]]", order).unwrap());
+/// ```
+///
 /// ```pass
 /// use fancy_regex::Regex;
 /// use korean_regex::*;
@@ -309,46 +461,274 @@ impl Order {
 /// let re = Regex::new(&pattern.unwrap()).unwrap();
 /// ```
 pub fn compilestr(pattern: &str, order: Order) -> Result<String, KoreanRegexError> {
-    let korean_regex_pattern_finder = Regex::new(
-        r"\[([0ㄱ-ㅎㅏ-ㅣ\^()-]*):([0ㄱ-ㅎㅏ-ㅣ\^()-]*)(:?)([0ㄱ-ㅎㅏ-ㅣ\^()-]*)(\|[^]]*)?\]",
-    )
-    .map_err(KoreanRegexError::RegexError)?;
-
-    let mut final_error: Option<KoreanRegexError> = None;
-    let result = korean_regex_pattern_finder
-        .replace_all(pattern, |captured: &regex::Captures<'_>| {
-            let chosungs = &captured[1];
-            let jungsungs = &captured[2];
-            let optional_delimiter = &captured[3];
-            let jongsungs = if optional_delimiter.is_empty() {
-                "0"
+    compilestr_all_errors(pattern, order).map_err(|mut errors| errors.remove(0))
+}
+
+/// `compilestr`과 같지만 첫 번째 오류에서 멈추지 않고 패턴 안의 모든 오류를 모아 반환합니다.
+///
+/// 오류는 패턴 안에서 나타난 순서대로 정렬되어 있으며, 오류가 있다면 적어도 하나 이상 들어 있습니다.
+/// 에디터나 린터처럼 한 번에 모든 문제를 보여줘야 할 때 유용합니다.
+///
+/// ```rust
+/// use korean_regex::*;
+///
+/// let pattern = "[ㄱ-::][ㄴ:ㅏ:ㄱ][ㄷ:(ㅗㅗ):ㄹ-ㄱ]";
+/// let errors = compilestr_all_errors(pattern, Order::Default).unwrap_err();
+/// let spans: Vec<_> = errors.iter().map(|error| &pattern[error.span()]).collect();
+/// assert_eq!(vec!["-", "(ㅗㅗ)", "ㄹ-ㄱ"], spans);
+/// ```
+pub fn compilestr_all_errors(pattern: &str, order: Order) -> Result<String, Vec<KoreanRegexError>> {
+    compile_pieces(pattern, &builder::CompileOptions::new(order))
+}
+
+/// `compilestr`의 엄격 모드입니다.
+///
+/// `compilestr`은 해당 자리에 올 수 없는 문자를 조용히 무시하고,
+/// 슬롯처럼 보이지만 슬롯 문법에 맞지 않는 괄호는 일반 문자 클래스로 남겨 둡니다.
+/// 엄격 모드에서는 이러한 경우와 어떤 문자에도 match할 수 없는 슬롯을 모두 오류로 취급합니다.
+///
+/// ```rust
+/// use korean_regex::*;
+///
+/// let order = Order::Default;
+/// // 초성 자리에 중성이 들어간 경우
+/// assert_eq!("[]", compilestr("[ㅏ::]", order).unwrap());
+/// match compilestr_strict("[ㅏ::]", order).unwrap_err() {
+///     KoreanRegexError::InvalidPhonemeError(_, chr, ..) => assert_eq!('ㅏ', chr),
+///     _ => panic!(),
+/// }
+///
+/// // 슬롯 문법에 맞지 않는 문자가 있는 경우
+/// let pattern = "[ㄱ:d:ㅇ]";
+/// assert_eq!("[ㄱ:d:ㅇ]", compilestr(pattern, order).unwrap());
+/// assert_eq!("d", &pattern[compilestr_strict(pattern, order).unwrap_err().span()]);
+/// ```
+pub fn compilestr_strict(pattern: &str, order: Order) -> Result<String, KoreanRegexError> {
+    KoreanRegexBuilder::new(pattern).order(order).strict(true).build_str()
+}
+
+fn compile_pieces(
+    pattern: &str,
+    options: &builder::CompileOptions,
+) -> Result<String, Vec<KoreanRegexError>> {
+    let pieces = parser::parse(pattern, options);
+    reference::compile_pieces(pattern, &pieces, options)
+}
+
+/// 패턴의 조각 하나를 컴파일합니다. 자모를 묶거나 요구하는 문법은 이미 풀려 있어야 합니다.
+fn compile_piece(
+    pattern: &str,
+    piece: &parser::Piece,
+    options: &builder::CompileOptions,
+) -> Result<String, Vec<KoreanRegexError>> {
+    match piece {
+        parser::Piece::Literal(literal) => Ok(literal.to_string()),
+        parser::Piece::MalformedSlot(class, span) => {
+            if options.strict {
+                Err(parser::malformed_slot_errors(pattern, span.clone(), options))
             } else {
-                &captured[4]
-            };
-            let other_one_letter_options = captured
-                .get(5)
-                .map(|other_options| &other_options.as_str()[1..])
-                .unwrap_or("");
-            match substitute(chosungs, jungsungs, jongsungs, order, true) {
-                Ok(result) => format!("[{}{}]", result, other_one_letter_options),
-                Err(error) => {
-                    final_error = Some(error);
-                    "(error)".to_string()
-                }
+                Ok(class.to_string())
             }
-        })
-        .into_owned();
-
-    if let Some(error) = final_error {
-        Err(error)
-    } else {
-        Ok(result)
+        }
+        parser::Piece::Slot(slot) => substitute::compile_slot(slot, options),
+        parser::Piece::ChosungQuery(query, span) => {
+            substitute::compile_chosung_query(query, span.clone(), options)
+        }
+        parser::Piece::HangulEscape(class, negated, span) => {
+            substitute::compile_hangul_escape(*class, *negated, span.clone(), options)
+        }
     }
 }
 
 /// 한국어 regex가 담긴 패턴을 받아 Regex로 컴파일합니다.
+///
+/// 컴파일된 결과는 패턴과 순서, 옵션을 키로 하는 전역 캐시에 보관되어
+/// 같은 패턴을 다시 컴파일할 때는 캐시된 Regex를 돌려줍니다.
+/// 캐시는 여러 스레드에서 함께 사용할 수 있으며 크기는 `set_cache_capacity`로 정할 수 있습니다.
+///
+/// `regex`의 옵션이나 다른 한국어 문법 옵션을 정하려면 `KoreanRegexBuilder`를 사용하세요.
+///
+/// ```rust
+/// use korean_regex::*;
+///
+/// let order = Order::Default;
+/// let first = compile("[ㄱ::]", order).unwrap();
+/// let second = compile("[ㄱ::]", order).unwrap();
+/// assert_eq!(first.as_str(), second.as_str());
+/// ```
 pub fn compile(pattern: &str, order: Order) -> Result<regex::Regex, KoreanRegexError> {
-    Regex::new(compilestr(pattern, order)?.as_str()).map_err(KoreanRegexError::RegexError)
+    KoreanRegexBuilder::new(pattern).order(order).build()
+}
+
+/// 엄격 모드로 패턴을 컴파일합니다. 자세한 내용은 `compilestr_strict`를 참고하세요.
+pub fn compile_strict(pattern: &str, order: Order) -> Result<regex::Regex, KoreanRegexError> {
+    KoreanRegexBuilder::new(pattern).order(order).strict(true).build()
+}
+
+/// 초성 검색 질의를 한국어 regex 패턴으로 바꿉니다.
+///
+/// 질의의 자음은 해당 자음이 초성인 모든 음절에 match하는 `[ㄱ::]` 형태의 슬롯으로,
+/// 그 외의 문자는 정규표현식에서 문자 그대로 match하도록 이스케이프된 문자로 바뀝니다.
+/// 결과는 `compile` 등에 그대로 넘기거나 다른 패턴과 이어 붙여 사용할 수 있습니다.
+///
+/// 패턴 안에 직접 적을 때는 `\c{ㄱㄴ}` 문법을 사용할 수 있습니다.
+///
+/// ```rust
+/// use korean_regex::*;
+///
+/// assert_eq!("[ㄱ::][ㄴ::]", chosung_pattern("ㄱㄴ"));
+/// assert_eq!("[ㅎ::]국\\?", chosung_pattern("ㅎ국?"));
+/// // 공백은 `x` 모드에서도 남도록 적음
+/// assert_eq!("[ㄱ::]\\x{20}[ㄴ::]", chosung_pattern("ㄱ ㄴ"));
+///
+/// let pattern = compile(&chosung_pattern("ㄱㄴ"), Order::Default).unwrap();
+/// assert!(pattern.is_match("가나"));
+/// assert!(pattern.is_match("고냥이"));
+/// assert!(!pattern.is_match("가다"));
+/// ```
+pub fn chosung_pattern(query: &str) -> String {
+    let (all_chosungs, ..) = Order::Default.order();
+    query
+        .chars()
+        .map(|chr| {
+            if all_chosungs.contains(&chr) {
+                format!("[{chr}::]")
+            } else if chr.is_whitespace() {
+                format!("\\x{{{:X}}}", chr as u32)
+            } else {
+                regex::escape(chr.encode_utf8(&mut [0; 4]))
+            }
+        })
+        .collect()
+}
+
+/// 입력 중인 검색어를 그 검색어로 시작할 수 있는 문자열에 match하는 한국어 regex 패턴으로 바꿉니다.
+///
+/// 마지막 음절은 아직 입력이 끝나지 않았을 수 있기에 앞으로 될 수 있는 음절들의 슬롯으로 바뀝니다.
+///
+/// * 종성이 없다면 종성이 더해지거나 중성이 이중 모음이 될 수 있습니다. (`구` → `국`, `궈`, `귄` 등)
+/// * 종성이 있다면 겹받침이 되거나, 다음 글자의 초성으로 넘어갈 수 있습니다. (`국` → `굵`, `구기` 등)
+/// * 자음만 있다면 해당 자음이 초성인 모든 음절이 될 수 있습니다. (`ㄱ` → `가`, `국` 등)
+///
+/// 그 외의 문자는 정규표현식에서 문자 그대로 match하도록 이스케이프됩니다.
+///
+/// ```rust
+/// use korean_regex::*;
+///
+/// assert_eq!("한[ㄱ:ㅜㅝㅞㅟ:]", prefix_pattern("한구"));
+/// assert_eq!("한(?:[ㄱ:ㅜ:ㄱㄳ]|[ㄱ:ㅜ][ㄱ::])", prefix_pattern("한국"));
+///
+/// let pattern = compile(&format!("^{}", prefix_pattern("한구")), Order::Default).unwrap();
+/// assert!(pattern.is_match("한국"));
+/// assert!(pattern.is_match("한구"));
+/// assert!(pattern.is_match("한궈"));
+/// assert!(!pattern.is_match("한가"));
+///
+/// let pattern = compile(&format!("^{}", prefix_pattern("한국")), Order::Default).unwrap();
+/// assert!(pattern.is_match("한국어"));
+/// assert!(pattern.is_match("한구기"));
+/// assert!(!pattern.is_match("한구"));
+/// ```
+pub fn prefix_pattern(query: &str) -> String {
+    let Some(last) = query.chars().last() else {
+        return String::new();
+    };
+    let mut pattern = regex::escape(&query[..query.len() - last.len_utf8()]);
+
+    let (all_chosungs, all_jungsungs, all_jongsungs_with_zero) = Order::Default.order();
+    // 앞의 자모와 합쳐져 겹자모가 될 수 있는 것들입니다. 된소리는 한 번에 입력되기에 제외합니다.
+    let grown = |first: char, all_phonemes: &[char]| -> String {
+        all_phonemes
+            .iter()
+            .filter(|chr| matches!(hangul::decompose_jamo(**chr), Some((a, b)) if a == first && a != b))
+            .collect()
+    };
+
+    match hangul::Syllable::from_char(last).map(hangul::Syllable::decompose) {
+        Some((chosung, jungsung, None)) => {
+            pattern.push_str(&format!("[{chosung}:{jungsung}{}:]", grown(jungsung, all_jungsungs)));
+        }
+        Some((chosung, jungsung, Some(jongsung))) => {
+            let grown_jongsungs = grown(jongsung, all_jongsungs_with_zero);
+            let moved = match hangul::decompose_jamo(jongsung) {
+                Some((first, second)) if first != second => Some((first.to_string(), second)),
+                _ => all_chosungs.contains(&jongsung).then(|| ("0".to_string(), jongsung)),
+            };
+            let syllable = format!("[{chosung}:{jungsung}:{jongsung}{grown_jongsungs}]");
+            match moved {
+                Some((remaining, next_chosung)) => pattern.push_str(&format!(
+                    "(?:{syllable}|[{chosung}:{jungsung}{}][{next_chosung}::])",
+                    if remaining == "0" { String::new() } else { format!(":{remaining}") },
+                )),
+                None => pattern.push_str(&syllable),
+            }
+        }
+        None if all_chosungs.contains(&last) => pattern.push_str(&format!("[{last}::]")),
+        None => pattern.push_str(&regex::escape(last.encode_utf8(&mut [0; 4]))),
+    }
+    pattern
+}
+
+/// 한국어 regex가 담긴 패턴을 바이트 열에서 사용할 수 있는 `regex::bytes::Regex`로 컴파일합니다.
+///
+/// 입력이 올바른 UTF-8이 아닐 수 있을 때 유용합니다.
+/// 자세한 내용은 [`KoreanRegexBuilder::build_bytes`]를 참고하세요.
+///
+/// ```rust
+/// use korean_regex::*;
+///
+/// let regex = compile_bytes("[::ㅇ]", Order::Default).unwrap();
+/// let mut haystack = b"\xC3\x28 ".to_vec();
+/// haystack.extend_from_slice("공항".as_bytes());
+/// let result: Vec<_> = regex.find_iter(&haystack).map(|m| m.as_bytes()).collect();
+/// assert_eq!(vec!["공".as_bytes(), "항".as_bytes()], result);
+/// ```
+pub fn compile_bytes(pattern: &str, order: Order) -> Result<regex::bytes::Regex, KoreanRegexError> {
+    KoreanRegexBuilder::new(pattern).order(order).build_bytes()
+}
+
+/// 여러 한국어 regex 패턴을 하나의 `regex::RegexSet`으로 컴파일합니다.
+///
+/// 반환된 RegexSet의 match 결과에 있는 인덱스는 넘겨준 패턴의 인덱스와 같습니다.
+/// 오류가 나면 어느 패턴에서 났는지를 [`KoreanRegexSetError::index`]로 알 수 있습니다.
+/// 모든 패턴의 한국어 문법을 먼저 확인한 뒤 정규표현식으로 컴파일하므로,
+/// 한국어 문법의 오류가 다른 패턴의 정규표현식 오류보다 먼저 보고됩니다.
+///
+/// ```rust
+/// use korean_regex::*;
+///
+/// let set = compile_set(["^[ㄱ::]", "[::ㅇ]$", "[ㅎ:ㅏ:]"], Order::Default).unwrap();
+/// let matched: Vec<_> = set.matches("공항").into_iter().collect();
+/// assert_eq!(vec![0, 1, 2], matched);
+/// let matched: Vec<_> = set.matches("감자").into_iter().collect();
+/// assert_eq!(vec![0], matched);
+/// ```
+pub fn compile_set<I, S>(patterns: I, order: Order) -> Result<regex::RegexSet, KoreanRegexSetError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let options = builder::CompileOptions::new(order);
+    let patterns: Vec<S> = patterns.into_iter().collect();
+    let mut compiled = Vec::with_capacity(patterns.len());
+    for (index, pattern) in patterns.iter().enumerate() {
+        let pattern = pattern.as_ref();
+        match compile_pieces(pattern, &options) {
+            Ok(string) => compiled.push(string),
+            Err(mut errors) => return Err(KoreanRegexSetError::new(Some(index), errors.remove(0))),
+        }
+    }
+
+    regex::RegexSet::new(&compiled).map_err(|set_error| {
+        // 어느 패턴이 문제인지 알기 위해 하나씩 다시 컴파일해 봄
+        for (index, (pattern, string)) in patterns.iter().zip(&compiled).enumerate() {
+            if let Err(error) = regex::Regex::new(string) {
+                let span = 0..pattern.as_ref().len();
+                return KoreanRegexSetError::new(Some(index), KoreanRegexError::RegexError(error, span));
+            }
+        }
+        KoreanRegexSetError::new(None, KoreanRegexError::RegexError(set_error, 0..0))
+    })
 }
 
 #[cfg(test)]
@@ -372,8 +752,246 @@ mod test {
             compile("[ㄱㄴㄷ:ㅏㅣ]", order).unwrap().to_string()
         );
         match compilestr("123[ㄱㄷㅎ:(ㄱㄱㄱ):ㅇ|은]", order).unwrap_err() {
-            KoreanRegexError::UnparenthesizingFailedError(_) => (),
+            KoreanRegexError::UnparenthesizingFailedError(..) => (),
             _ => panic!("Should raise UnparenthesizingFailedError"),
         }
     }
+
+    #[test]
+    fn test_compilestr_all_errors() {
+        let order = Order::Default;
+        let pattern = "[ㄱ::]abc[0:ㅏ:ㄱ][ㄱ-ㄴ-ㄷ:ㅏㅣ(ㅡㅡ):ㄹ-ㄱ]";
+        let errors = compilestr_all_errors(pattern, order).unwrap_err();
+        let spans: Vec<_> = errors.iter().map(|error| &pattern[error.span()]).collect();
+        assert_eq!(vec!["[0:ㅏ:ㄱ]", "(ㅡㅡ)", "ㄹ-ㄱ"], spans);
+        match &errors[..] {
+            [
+                KoreanRegexError::InvalidZeroPatternError(..),
+                KoreanRegexError::UnparenthesizingFailedError(..),
+                KoreanRegexError::InvalidHyphenError(..),
+            ] => (),
+            _ => panic!("Should raise errors in order"),
+        }
+
+        assert_eq!(
+            "[가-깋][각]",
+            compilestr_all_errors("[ㄱ::][ㄱ:ㅏ:ㄱ]", order).unwrap()
+        );
+        // compilestr은 첫 번째 오류를 반환함
+        assert_eq!(
+            pattern.find("[0").unwrap(),
+            compilestr(pattern, order).unwrap_err().span().start
+        );
+    }
+
+    #[test]
+    fn test_compilestr_strict() {
+        let order = Order::Default;
+        let strict_spans = |pattern: &'static str| -> Vec<&'static str> {
+            KoreanRegexBuilder::new(pattern)
+                .order(order)
+                .strict(true)
+                .build_str_all_errors()
+                .unwrap_err()
+                .iter()
+                .map(|error| &pattern[error.span()])
+                .collect()
+        };
+
+        // 엄격 모드가 아닐 때와 같은 결과
+        assert_eq!(
+            compilestr("[ㄱ-ㄷ:^ㅏ:0ㄴ|a]b[ㄱ::]", order).unwrap(),
+            compilestr_strict("[ㄱ-ㄷ:^ㅏ:0ㄴ|a]b[ㄱ::]", order).unwrap()
+        );
+        assert_eq!("[a-z:]", compilestr_strict("[a-z:]", order).unwrap());
+
+        // 무시되던 문자
+        assert_eq!(vec!["ㅏ"], strict_spans("[ㅏ::]"));
+        assert_eq!(vec!["0"], strict_spans("[0ㄱ:ㅏ:ㄴ]"));
+        assert_eq!(vec!["^"], strict_spans("[ㄱ^ㄴ::]"));
+        assert_eq!(vec!["ㅏ", "ㄱ", "ㅏ"], strict_spans("[ㅏ:ㄱ:ㅏ]"));
+
+        // 슬롯 문법에 맞지 않는 괄호
+        assert_eq!(vec!["d"], strict_spans("123[ㄱㄷㅎ:d:ㅇ|은]"));
+        assert_eq!(vec![":"], strict_spans("[ㄱ:ㄴ:ㄷ:ㄹ]"));
+
+        // match할 수 있는 문자가 없는 슬롯
+        assert_eq!(vec!["[^ㄱ-ㅎ::]"], strict_spans("a[^ㄱ-ㅎ::]"));
+
+        // 닫히지 않은 괄호는 엄격 모드가 아니어도 오류
+        assert_eq!("(ㅗ", &"[ㄱ:(ㅗ:]"[compilestr("[ㄱ:(ㅗ:]", order).unwrap_err().span()]);
+
+        let pattern = compile_strict("[ㄱ:ㅏ:ㄱ]", order).unwrap();
+        assert!(pattern.is_match("각"));
+    }
+
+    #[test]
+    fn test_error_span() {
+        let order = Order::Default;
+        let span_of = |pattern: &'static str| &pattern[compilestr(pattern, order).unwrap_err().span()];
+
+        assert_eq!("(ㄱㄱㄱ)", span_of("123[ㄱㄷㅎ:(ㄱㄱㄱ):ㅇ|은]"));
+        assert_eq!("(", span_of("[ㄱ:((ㅗㅏ):]"));
+        assert_eq!(")", span_of("[ㄱ:ㅏ):]"));
+        assert_eq!("-", span_of("[ㄱ::][ㄱ::-ㄹ]"));
+        assert_eq!("ㄹ-ㄱ", span_of("[ㄱ::][ㄱ::ㄹ-ㄱ]"));
+        assert_eq!("ㅏ", span_of("[ㄱ::][ㄴ-ㅏ::]"));
+        assert_eq!("[0:ㅏ:ㄱ]", span_of("abc[0:ㅏ:ㄱ]"));
+
+        let pattern = "([ㄱ::]";
+        match compile(pattern, order).unwrap_err() {
+            KoreanRegexError::RegexError(_, span) => assert_eq!(0..pattern.len(), span),
+            _ => panic!("Should raise RegexError"),
+        }
+    }
+
+    #[test]
+    fn test_compilestr_keeps_regex_syntax() {
+        let order = Order::Default;
+        assert_eq!(r"\[ㄱ:ㅏ:ㄱ]", compilestr(r"\[ㄱ:ㅏ:ㄱ]", order).unwrap());
+        assert_eq!(r"\\[각]", compilestr(r"\\[ㄱ:ㅏ:ㄱ]", order).unwrap());
+        assert_eq!("[a[ㄱ:ㅏ:ㄱ]]", compilestr("[a[ㄱ:ㅏ:ㄱ]]", order).unwrap());
+        assert_eq!(
+            "(?x) # [ㄱ:ㅏ:ㄱ]\n[각]",
+            compilestr("(?x) # [ㄱ:ㅏ:ㄱ]\n[ㄱ:ㅏ:ㄱ]", order).unwrap()
+        );
+        assert_eq!(
+            r"[[:alpha:]ㄱ:ㅏ]|[각\]]",
+            compilestr(r"[[:alpha:]ㄱ:ㅏ]|[ㄱ:ㅏ:ㄱ|\]]", order).unwrap()
+        );
+
+        let pattern = compile(r"(?x) \[ [ㄱ:ㅏ:ㄱ] \] # [ㄴ:ㅏ:ㄱ]", order).unwrap();
+        assert!(pattern.is_match("[각]"));
+        assert!(!pattern.is_match("[낙]"));
+    }
+
+    #[test]
+    fn test_compound_jongsung() {
+        let order = Order::Default;
+        assert_eq!(compilestr("[::ㄿ]", order).unwrap(), compilestr("[::(ㄹㅍ)]", order).unwrap());
+        assert_eq!("[쌌]", compilestr("[(ㅅㅅ):ㅏ:(ㅅㅅ)]", order).unwrap());
+    }
+
+    #[test]
+    fn test_old_hangul() {
+        let order = Order::OldHangul;
+        // 옛한글 순서가 아니라면 옛한글 자모가 있는 괄호는 슬롯이 아님
+        assert_eq!("[ㅿ:ㅏ:]", compilestr("[ㅿ:ㅏ:]", Order::Default).unwrap());
+
+        assert_eq!("(?:[ᅀ][ᅡ])", compilestr("[ㅿ:ㅏ]", order).unwrap());
+        assert_eq!("(?:[가각]|[ᄀ][ᅡ][ᆨᇫ]?)", compilestr("[ㄱ:ㅏ:0ㄱㅿ]", order).unwrap());
+        assert_eq!("(?:[나a]|[ᄂ][ᅡᆞ])", compilestr("[ㄴ:ㅏㆍ|a]", order).unwrap());
+        assert_eq!("[ㅿㆁ]", compilestr("[ㅿㆁ:0:0]", order).unwrap());
+        // 옛한글 자모는 현대 한글 자모 뒤에 오기에 하이픈으로 함께 쓸 수 있음
+        assert_eq!("[ㅎㅥㅦ]", compilestr("[ㅎ-ㅦ:0:0]", order).unwrap());
+        assert_eq!("(?:[ᄒ][ᆞ])", compilestr_strict("[ㅎ:ㆍ]", order).unwrap());
+
+        match compilestr_strict("[ㆍ:ㅏ]", order).unwrap_err() {
+            KoreanRegexError::InvalidPhonemeError(_, chr, kind, _) => {
+                assert_eq!('ㆍ', chr);
+                assert_eq!(Some(SlotKind::Chosung), kind);
+            }
+            _ => panic!("Should raise InvalidPhonemeError"),
+        }
+
+        let regex = compile("^[ㅎ:ㆍ:ㄴ][ㄱ:ㅡ:ㄹ]$", order).unwrap();
+        assert!(regex.is_match("\u{1112}\u{119E}\u{11AB}글"));
+        assert!(!regex.is_match("한글"));
+    }
+
+    #[test]
+    fn test_chosung_query() {
+        let order = Order::Default;
+        assert_eq!("[가-깋][나-닣]", compilestr(r"\c{ㄱㄴ}", order).unwrap());
+        assert_eq!("a[하-힣]국\\.b", compilestr(r"a\c{ㅎ국.}b", order).unwrap());
+        assert_eq!(compilestr(&chosung_pattern("ㅎ국."), order).unwrap(), compilestr(r"\c{ㅎ국.}", order).unwrap());
+        assert_eq!("ㄳㅏ", compilestr(r"\c{ㄳㅏ}", order).unwrap());
+        // 공백은 `x` 모드에서도 남도록 적음
+        assert_eq!("[가-깋]\\x{20}[나-닣]", compilestr(r"\c{ㄱ ㄴ}", order).unwrap());
+        assert_eq!(compilestr(&chosung_pattern("ㄱ ㄴ"), order).unwrap(), compilestr(r"\c{ㄱ ㄴ}", order).unwrap());
+        let regex = compile(r"(?x)^\c{ㄱ ㄴ}$", order).unwrap();
+        assert!(regex.is_match("고 니"));
+        assert!(!regex.is_match("고니"));
+        // 빈 초성 검색은 모든 곳에 match하지 않도록 오류로 취급함
+        let error = compilestr(r"a\c{}", order).unwrap_err();
+        assert_eq!(ErrorKind::InvalidSlot, error.kind());
+        assert_eq!(r"\c{}", &r"a\c{}"[error.span()]);
+
+        let pattern = r"ab\c{ㄱㄳ}";
+        assert_eq!("ab[가-깋]ㄳ", compilestr(pattern, order).unwrap());
+        let error = compilestr_strict(pattern, order).unwrap_err();
+        assert_eq!("ㄳ", &pattern[error.span()]);
+        assert_eq!(Some(SlotKind::Chosung), error.slot());
+
+        // 다른 옵션도 초성 검색에 적용됨
+        let regex = KoreanRegexBuilder::new(r"^\c{ㅎㄱ}$").partial(true).build().unwrap();
+        assert!(regex.is_match("하ㄱ"));
+        assert!(regex.is_match("한국"));
+    }
+
+    #[test]
+    fn test_hangul_escape() {
+        let order = Order::Default;
+        assert_eq!("[가-힣]a[ㄱ-ㅣ]", compilestr(r"\가a\J", order).unwrap());
+        assert_eq!("[가-힣ㄱ-ㅣ]", compilestr(r"\H", order).unwrap());
+        assert_eq!("[^가-힣][^ㄱ-ㅣ][^가-힣ㄱ-ㅣ]", compilestr(r"\P{가}\P{J}\P{H}", order).unwrap());
+        // 이스케이프된 `\`와 클래스 안, 문자 그대로의 `^`를 나타내는 `\^`는 그대로 둠
+        assert_eq!(r"\\가[\P{H}]", compilestr(r"\\가[\P{H}]", order).unwrap());
+        assert_eq!(r"\^H\^J\^가\^Hello", compilestr(r"\^H\^J\^가\^Hello", order).unwrap());
+        assert!(compile(r"^\^Hello$", order).unwrap().is_match("^Hello"));
+        assert_eq!("[ㄱ-ㅣㅥ-ㆎ]", compilestr(r"\J", Order::OldHangul).unwrap());
+
+        let regex = compile(r"^\H+ \P{H}+$", order).unwrap();
+        assert!(regex.is_match("ㅋㅋ한글 abc"));
+        assert!(!regex.is_match("한글 abc가"));
+
+        // 다른 옵션도 이스케이프에 적용됨
+        let regex = KoreanRegexBuilder::new(r"^\가\J$").compatibility_forms(true).build().unwrap();
+        assert!(regex.is_match("㈎ㄱ"));
+        assert!(regex.is_match("가ﾡ"));
+        let regex = KoreanRegexBuilder::new(r"^\H$").conjoining(true).build().unwrap();
+        assert!(regex.is_match("\u{1100}\u{1161}"));
+        assert!(regex.is_match("ㅏ"));
+        let regex = KoreanRegexBuilder::new(r"^\P{가}$").conjoining(true).build().unwrap();
+        assert!(regex.is_match("a"));
+        assert!(!regex.is_match("가"));
+    }
+
+    #[test]
+    fn test_prefix_pattern() {
+        assert_eq!("", prefix_pattern(""));
+        assert_eq!("a\\.", prefix_pattern("a."));
+        assert_eq!("한[ㄱ::]", prefix_pattern("한ㄱ"));
+        assert_eq!("[ㄷ:ㅗㅘㅙㅚ:]", prefix_pattern("도"));
+        assert_eq!("(?:[ㄷ:ㅏ:ㄹㄺㄻㄼㄽㄾㄿㅀ]|[ㄷ:ㅏ][ㄹ::])", prefix_pattern("달"));
+        // 겹받침은 뒤의 자음만 다음 글자로 넘어감
+        assert_eq!("(?:[ㄷ:ㅏ:ㄺ]|[ㄷ:ㅏ:ㄹ][ㄱ::])", prefix_pattern("닭"));
+        // 된소리 받침은 통째로 넘어감
+        assert_eq!("(?:[ㄱ:ㅏ:ㅆ]|[ㄱ:ㅏ][ㅆ::])", prefix_pattern("갔"));
+
+        let pattern = compile(&format!("^{}$", prefix_pattern("닭")), Order::Default).unwrap();
+        assert!(pattern.is_match("닭"));
+        assert!(pattern.is_match("달걀"));
+        assert!(!pattern.is_match("달"));
+    }
+
+    #[test]
+    fn test_compile_set() {
+        let set = compile_set(Vec::<String>::new(), Order::Default).unwrap();
+        assert!(set.is_empty());
+
+        let set = compile_set(vec!["[ㄱ-ㄹ:0:0]".to_string(), "[ㄱ::]".to_string()], Order::RegularFirst).unwrap();
+        assert_eq!(vec!["[ㄱㄴㄷㄹ]", "[가-깋]"], set.patterns());
+
+        let error = compile_set(["[ㄱ::]", "[ㄱ::](", "[ㄱ:ㅏ:ㄱ]"], Order::Default).unwrap_err();
+        assert_eq!(Some(1), error.index());
+        assert_eq!(ErrorKind::Regex, error.error().kind());
+        assert_eq!(0..8, error.error().span());
+        assert!(error.to_string().starts_with("pattern 1: "));
+
+        // 한국어 문법의 오류가 정규표현식 오류보다 먼저 보고됨
+        let error = compile_set(["[ㄱ::](", "[0:ㅏ:ㄱ]"], Order::Default).unwrap_err();
+        assert_eq!(Some(1), error.index());
+        assert_eq!(ErrorKind::InvalidZeroPattern, error.into_error().kind());
+    }
 }
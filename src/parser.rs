@@ -0,0 +1,788 @@
+//! 정규표현식 문법을 따라 패턴을 훑으며 한국어 슬롯(`[초성:중성:종성]`)을 찾습니다.
+//!
+//! 이스케이프, 다른 문자 클래스 안에 중첩된 클래스, `(?x)` 모드의 주석 등은
+//! 정규표현식 문법 그대로 건너뛰며, 슬롯이 아닌 부분은 원래 패턴의 문자열을 그대로 가리킵니다.
+
+use std::ops::Range;
+
+use crate::builder::{CompileOptions, NamedSet};
+use crate::{hangul, KoreanRegexError, Order};
+
+/// 패턴을 이루는 조각입니다.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Piece<'a> {
+    /// 슬롯이 아닌 부분입니다. 결과에 그대로 들어갑니다.
+    Literal(&'a str),
+    /// `[초성:중성:종성|기타]` 형태의 슬롯입니다.
+    Slot(Slot<'a>),
+    /// `[ㄱ:d:ㄴ]`처럼 슬롯처럼 보이지만 슬롯 문법에 맞지 않는 문자 클래스입니다.
+    ///
+    /// 엄격 모드가 아니라면 일반 문자 클래스처럼 그대로 결과에 들어갑니다.
+    MalformedSlot(&'a str, Range<usize>),
+    /// `\c{ㅎ국}`과 같은 초성 검색 문법입니다.
+    ///
+    /// 중괄호 안의 문자열과 원래 패턴에서 `\c{`부터 `}`까지의 바이트 범위를 가집니다.
+    ChosungQuery(&'a str, Range<usize>),
+    /// `\가`, `\P{J}`와 같은 한글 축약 이스케이프입니다.
+    ///
+    /// 나타내는 문자들과 `\P{...}`로 부정되었는지 여부, 원래 패턴에서 `\`부터 이스케이프 끝까지의 바이트 범위를 가집니다.
+    HangulEscape(HangulClass, bool, Range<usize>),
+}
+
+/// 한글 축약 이스케이프가 나타내는 문자들입니다.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum HangulClass {
+    /// `\가`: 모든 음절입니다.
+    Syllable,
+    /// `\J`: 모든 호환용 자모입니다.
+    Jamo,
+    /// `\H`: 모든 음절과 호환용 자모입니다.
+    Hangul,
+}
+
+/// 패턴 안에서 찾은 하나의 슬롯입니다.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Slot<'a> {
+    /// 원래 패턴에서 `[`부터 `]`까지의 바이트 범위입니다.
+    pub(crate) span: Range<usize>,
+    pub(crate) chosungs: Part<'a>,
+    pub(crate) jungsungs: Part<'a>,
+    /// `[ㄱ:ㅏ]`처럼 종성 자리가 생략되었다면 None입니다.
+    pub(crate) jongsungs: Option<Part<'a>>,
+    /// `|` 뒤에 오는 추가 문자들입니다.
+    pub(crate) others: Option<&'a str>,
+    /// `[(?<c>ㄱㄴ):ㅏ:]`처럼 초성, 중성, 종성 자리에 붙인 이름입니다.
+    pub(crate) names: [Option<&'a str>; 3],
+}
+
+/// 슬롯의 초성, 중성, 종성 중 한 자리에 적힌 문자열입니다.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) struct Part<'a> {
+    pub(crate) text: &'a str,
+    /// 원래 패턴에서 `text`가 시작하는 바이트 위치입니다.
+    pub(crate) start: usize,
+}
+
+/// 패턴을 슬롯과 슬롯이 아닌 부분으로 나눕니다.
+///
+/// 이 함수는 실패하지 않습니다. 정규표현식으로서 잘못된 부분이 있다면
+/// 그 부분은 그대로 남겨 두고 오류는 정규표현식 엔진이 보고하도록 합니다.
+pub(crate) fn parse<'a>(pattern: &'a str, options: &CompileOptions) -> Vec<Piece<'a>> {
+    Parser {
+        pattern,
+        syntax: Syntax::new(pattern, options),
+        pos: 0,
+        extended: false,
+        groups: Vec::new(),
+    }
+    .parse()
+}
+
+/// 패턴에서 슬롯을 찾는 데 필요한 설정입니다.
+struct Syntax<'s> {
+    /// 옛한글 호환용 자모(`ㅿ`, `ㆍ` 등)를 슬롯에 올 수 있는 문자로 취급할지 여부입니다.
+    archaic: bool,
+    /// 빌더에 등록된 자모 집합입니다.
+    named_sets: &'s [NamedSet],
+    /// 패턴에서 `\k<이름>`으로 요구하는 이름들입니다.
+    references: Vec<&'s str>,
+}
+
+impl<'s> Syntax<'s> {
+    fn new(pattern: &'s str, options: &'s CompileOptions) -> Self {
+        let references = pattern
+            .match_indices("\\k<")
+            .filter_map(|(start, _)| {
+                let length = reference_token_len(&pattern[start..])?;
+                Some(&pattern[start + "\\k<".len()..start + length - 1])
+            })
+            .collect();
+        Syntax {
+            archaic: options.order == Order::OldHangul,
+            named_sets: &options.named_sets,
+            references,
+        }
+    }
+
+    /// `@이름`이 기본으로 제공되거나 빌더에 등록된 자모 집합을 가리키는지 확인합니다.
+    fn is_known_set(&self, name: &str) -> bool {
+        self.named_sets.iter().any(|set| set.name == name) || hangul::named_jamo_set(name).is_some()
+    }
+}
+
+/// 슬롯의 초성, 중성, 종성 자리에 올 수 있는 문자인지 확인합니다.
+fn is_slot_char(chr: char, archaic: bool) -> bool {
+    matches!(chr, '0' | 'ㄱ'..='ㅎ' | 'ㅏ'..='ㅣ' | '^' | '(' | ')' | '-') || (archaic && is_archaic_jamo(chr))
+}
+
+/// `@경음`처럼 이름이 붙은 자모 집합의 이름에 올 수 있는 문자인지 확인합니다.
+pub(crate) fn is_set_name_char(chr: char) -> bool {
+    matches!(chr, '가'..='힣' | '_') || chr.is_ascii_alphanumeric()
+}
+
+/// 문자열이 자모를 이름에 묶는 `<이름>`이나 묶인 자모를 요구하는 `\k<이름>`으로 시작한다면 그 길이를 반환합니다.
+pub(crate) fn reference_token_len(text: &str) -> Option<usize> {
+    let name_start = if text.starts_with("\\k<") {
+        "\\k<".len()
+    } else if text.starts_with('<') {
+        "<".len()
+    } else {
+        return None;
+    };
+    let name_length = text[name_start..].find('>')?;
+    let name = &text[name_start..name_start + name_length];
+    (!name.is_empty() && name.chars().all(is_set_name_char)).then_some(name_start + name_length + 1)
+}
+
+/// 슬롯의 음소 부분을 이루는 조각입니다.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Token<'a> {
+    /// `0`, `^`, 자모처럼 한 글자로 된 조각입니다.
+    Char(char),
+    /// 자모를 이름에 묶는 `<이름>`입니다.
+    Binding(&'a str),
+    /// 묶인 자모를 요구하는 `\k<이름>`입니다.
+    Reference(&'a str),
+    /// 자리에 이름을 붙이는 `(?<이름>`입니다.
+    PartName(&'a str),
+    /// 이름이 붙은 자모 집합 `@이름`입니다.
+    Set(&'a str),
+}
+
+/// 슬롯의 음소 부분을 조각으로 나누어 각 조각의 바이트 위치, 문자열과 함께 반환합니다.
+fn slot_tokens(phonemes: &str) -> impl Iterator<Item = (usize, &str, Token<'_>)> {
+    let mut index = 0;
+    std::iter::from_fn(move || {
+        let rest = &phonemes[index..];
+        let chr = rest.chars().next()?;
+        let (token, length) = if let Some(length) = reference_token_len(rest) {
+            match rest.strip_prefix("\\k<") {
+                Some(name) => (Token::Reference(&name[..length - "\\k<>".len()]), length),
+                None => (Token::Binding(&rest[1..length - 1]), length),
+            }
+        } else if let Some(length) = rest.strip_prefix("(?").and_then(reference_token_len) {
+            (Token::PartName(&rest["(?<".len().."(?".len() + length - 1]), "(?".len() + length)
+        } else if chr == '@' && rest[1..].starts_with(is_set_name_char) {
+            let length = rest[1..].find(|chr| !is_set_name_char(chr)).map_or(rest.len(), |length| length + 1);
+            (Token::Set(&rest[1..length]), length)
+        } else {
+            (Token::Char(chr), chr.len_utf8())
+        };
+        let start = index;
+        index += length;
+        Some((start, &rest[..length], token))
+    })
+}
+
+/// 슬롯의 음소 부분에서 슬롯에 올 수 없는 문자들의 바이트 위치를 찾습니다.
+///
+/// `@` 바로 뒤에 이어지는 이름은 자모 집합의 이름이기에 슬롯에 올 수 있습니다.
+/// `<이름>`과 `\k<이름>`, 자리에 이름을 붙이는 `(?<이름>` 또한 슬롯에 올 수 있습니다.
+/// 다만 `[@abc:]`나 `[<a>:]`처럼 슬롯의 내용이 없는 클래스는 원래의 정규표현식 문법을 따르도록 이 문법들을 인정하지 않습니다.
+fn invalid_slot_chars(phonemes: &str, syntax: &Syntax) -> Vec<usize> {
+    let slot_like = looks_like_slot(phonemes, syntax);
+    let mut invalid = Vec::new();
+    for (start, text, token) in slot_tokens(phonemes) {
+        if slot_like && !matches!(token, Token::Char(_)) {
+            continue;
+        }
+        for (index, chr) in text.char_indices() {
+            if chr != ':' && !is_slot_char(chr, syntax.archaic) {
+                invalid.push(start + index);
+            }
+        }
+    }
+    invalid
+}
+
+/// 옛한글 호환용 자모(U+3165–U+318E)인지 확인합니다.
+fn is_archaic_jamo(chr: char) -> bool {
+    matches!(chr, '\u{3165}'..='\u{318E}')
+}
+
+/// 문자 클래스의 내용을 `|` 앞의 음소 부분과 뒤의 추가 문자 부분으로 나눕니다.
+fn split_others(content: &str) -> (&str, Option<&str>) {
+    match content.find('|') {
+        Some(index) => (&content[..index], Some(&content[index + 1..])),
+        None => (content, None),
+    }
+}
+
+/// `:`와 슬롯의 내용이 들어 있어 슬롯을 의도한 것으로 보이는지 확인합니다.
+///
+/// 한글 자모와, 기본으로 제공되거나 빌더에 등록된 자모 집합의 `@이름`, 자리에 이름을 붙이는 `(?<이름>`을 슬롯의 내용으로 봅니다.
+/// 문자 클래스 안의 `\k`는 정규표현식으로서 잘못된 이스케이프이기에 `\k<이름>`도 슬롯의 내용으로 보며,
+/// `<이름>`은 패턴 어딘가에서 `\k<이름>`으로 요구할 때 슬롯의 내용으로 봅니다.
+fn looks_like_slot(phonemes: &str, syntax: &Syntax) -> bool {
+    phonemes.contains(':')
+        && slot_tokens(phonemes).any(|(_, _, token)| match token {
+            Token::Char(chr) => matches!(chr, 'ㄱ'..='ㅣ') || (syntax.archaic && is_archaic_jamo(chr)),
+            Token::Set(name) => syntax.is_known_set(name),
+            Token::Binding(name) => syntax.references.contains(&name),
+            Token::Reference(_) | Token::PartName(_) => true,
+        })
+}
+
+/// `[`부터 `]`까지의 문자 클래스가 슬롯이라면 슬롯으로 변환합니다.
+fn parse_slot<'a>(pattern: &'a str, span: Range<usize>, syntax: &Syntax) -> Option<Slot<'a>> {
+    let content = &pattern[span.start + 1..span.end - 1];
+    let (phonemes, others) = split_others(content);
+    if !invalid_slot_chars(phonemes, syntax).is_empty() {
+        return None;
+    }
+
+    let mut start = span.start + 1;
+    let mut parts = phonemes.split(':').map(|text| {
+        let part = Part { text, start };
+        start += text.len() + 1;
+        unname(part)
+    });
+    let (chosungs, chosung_name) = parts.next()??;
+    let (jungsungs, jungsung_name) = parts.next()??;
+    let (jongsungs, jongsung_name) = match parts.next() {
+        Some(part) => {
+            let (part, name) = part?;
+            (Some(part), name)
+        }
+        None => (None, None),
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some(Slot {
+        span,
+        chosungs,
+        jungsungs,
+        jongsungs,
+        others,
+        names: [chosung_name, jungsung_name, jongsung_name],
+    })
+}
+
+/// `(?<이름>ㄱㄴ)`처럼 이름이 붙은 자리라면 이름과 괄호 안의 부분으로 나눕니다.
+///
+/// 이름을 여는 괄호가 닫히지 않았다면 `None`입니다.
+fn unname<'a>(part: Part<'a>) -> Option<(Part<'a>, Option<&'a str>)> {
+    let Some(length) = part.text.strip_prefix("(?").and_then(reference_token_len) else {
+        return Some((part, None));
+    };
+    let inner_start = "(?".len() + length;
+    let inner = part.text[inner_start..].strip_suffix(')')?;
+    let name = &part.text["(?<".len()..inner_start - 1];
+    Some((Part { text: inner, start: part.start + inner_start }, Some(name)))
+}
+
+/// 슬롯처럼 보이지만 슬롯 문법에 맞지 않는 문자 클래스의 오류를 모두 찾습니다.
+pub(crate) fn malformed_slot_errors(
+    pattern: &str,
+    span: Range<usize>,
+    options: &CompileOptions,
+) -> Vec<KoreanRegexError> {
+    let content_start = span.start + 1;
+    let (phonemes, _) = split_others(&pattern[content_start..span.end - 1]);
+
+    let invalid = invalid_slot_chars(phonemes, &Syntax::new(pattern, options));
+    let mut errors = Vec::new();
+    let mut colons = 0;
+    for (index, chr) in phonemes.char_indices() {
+        let offset = content_start + index;
+        if chr == ':' {
+            colons += 1;
+            if colons == 3 {
+                errors.push(KoreanRegexError::InvalidSlotError(
+                    "Slot cannot have more than three parts.".to_string(),
+                    offset..offset + 1,
+                ));
+            }
+        } else if invalid.contains(&index) {
+            errors.push(KoreanRegexError::InvalidPhonemeError(
+                format!("Character `{chr}` cannot be used inside a slot."),
+                chr,
+                None,
+                offset..offset + chr.len_utf8(),
+            ));
+        }
+    }
+    errors
+}
+
+/// 정규표현식 조각을 통째로 여러 번 나열해도 되는지 확인합니다.
+///
+/// 괄호의 짝이 맞고, 괄호 바깥에 `|`가 없으며, 나열하면 번호나 이름이 겹치게 되는 캡처 그룹이 없어야 합니다.
+pub(crate) fn is_self_contained(text: &str) -> bool {
+    let mut parser = Parser {
+        pattern: text,
+        syntax: Syntax {
+            archaic: false,
+            named_sets: &[],
+            references: Vec::new(),
+        },
+        pos: 0,
+        extended: false,
+        groups: Vec::new(),
+    };
+    while let Some(chr) = parser.peek() {
+        match chr {
+            '\\' => parser.skip_escape(),
+            '[' => {
+                if parser.skip_class().is_none() {
+                    return false;
+                }
+            }
+            '(' => {
+                parser.bump();
+                if parser.peek() != Some('?') || parser.rest().starts_with("?P<") || parser.rest().starts_with("?<") {
+                    return false;
+                }
+                parser.groups.push(parser.extended);
+                parser.skip_group_flags();
+            }
+            ')' => {
+                parser.bump();
+                match parser.groups.pop() {
+                    Some(extended) => parser.extended = extended,
+                    None => return false,
+                }
+            }
+            '|' if parser.groups.is_empty() => return false,
+            '#' if parser.extended => parser.skip_comment(),
+            _ => {
+                parser.bump();
+            }
+        }
+    }
+    parser.groups.is_empty()
+}
+
+struct Parser<'a, 's> {
+    pattern: &'a str,
+    syntax: Syntax<'s>,
+    pos: usize,
+    /// `x` 플래그가 켜져 있어 `#` 뒤가 주석으로 취급되는지 여부입니다.
+    extended: bool,
+    /// 그룹에 들어갈 때의 `extended` 값입니다. 그룹이 닫히면 이 값으로 되돌립니다.
+    groups: Vec<bool>,
+}
+
+impl<'a> Parser<'a, '_> {
+    fn rest(&self) -> &'a str {
+        &self.pattern[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let chr = self.peek()?;
+        self.pos += chr.len_utf8();
+        Some(chr)
+    }
+
+    fn parse(mut self) -> Vec<Piece<'a>> {
+        let mut pieces = Vec::new();
+        let mut literal_start = 0;
+
+        while let Some(chr) = self.peek() {
+            match chr {
+                '\\' if self.rest().starts_with("\\c{") => {
+                    let start = self.pos;
+                    let Some(length) = self.rest().find('}') else {
+                        // 닫히지 않은 초성 검색 문법입니다. 오류는 정규표현식 엔진이 보고합니다.
+                        self.skip_escape();
+                        continue;
+                    };
+                    let end = start + length + 1;
+                    self.pos = end;
+                    if literal_start < start {
+                        pieces.push(Piece::Literal(&self.pattern[literal_start..start]));
+                    }
+                    pieces.push(Piece::ChosungQuery(&self.pattern[start + "\\c{".len()..end - 1], start..end));
+                    literal_start = end;
+                }
+                '\\' => match hangul_escape(self.rest()) {
+                    Some((class, negated, length)) => {
+                        let start = self.pos;
+                        let end = start + length;
+                        self.pos = end;
+                        if literal_start < start {
+                            pieces.push(Piece::Literal(&self.pattern[literal_start..start]));
+                        }
+                        pieces.push(Piece::HangulEscape(class, negated, start..end));
+                        literal_start = end;
+                    }
+                    None => self.skip_escape(),
+                },
+                '[' => {
+                    let start = self.pos;
+                    let Some(end) = self.skip_class() else {
+                        // 닫히지 않은 클래스입니다. 나머지는 모두 그대로 둡니다.
+                        break;
+                    };
+                    let piece = match parse_slot(self.pattern, start..end, &self.syntax) {
+                        Some(slot) => Piece::Slot(slot),
+                        None => {
+                            let class = &self.pattern[start..end];
+                            if !looks_like_slot(split_others(&class[1..class.len() - 1]).0, &self.syntax) {
+                                continue;
+                            }
+                            Piece::MalformedSlot(class, start..end)
+                        }
+                    };
+                    if literal_start < start {
+                        pieces.push(Piece::Literal(&self.pattern[literal_start..start]));
+                    }
+                    pieces.push(piece);
+                    literal_start = end;
+                }
+                '(' => {
+                    self.bump();
+                    self.groups.push(self.extended);
+                    if self.peek() == Some('?') {
+                        self.skip_group_flags();
+                    }
+                }
+                ')' => {
+                    self.bump();
+                    if let Some(extended) = self.groups.pop() {
+                        self.extended = extended;
+                    }
+                }
+                '#' if self.extended => self.skip_comment(),
+                _ => {
+                    self.bump();
+                }
+            }
+        }
+
+        if literal_start < self.pattern.len() {
+            pieces.push(Piece::Literal(&self.pattern[literal_start..]));
+        }
+        pieces
+    }
+
+    /// `\`로 시작하는 이스케이프를 건너뜁니다. `\p{Hangul}`처럼 중괄호가 있다면 중괄호까지 건너뜁니다.
+    fn skip_escape(&mut self) {
+        self.bump();
+        if matches!(self.bump(), Some('x' | 'u' | 'U' | 'p' | 'P')) && self.peek() == Some('{') {
+            while let Some(chr) = self.bump() {
+                if chr == '}' {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// `x` 모드에서 `#`부터 줄 끝까지의 주석을 건너뜁니다.
+    fn skip_comment(&mut self) {
+        while let Some(chr) = self.bump() {
+            if chr == '\n' {
+                break;
+            }
+        }
+    }
+
+    /// `(?` 바로 다음부터 그룹의 플래그나 이름을 읽어 `x` 플래그를 반영합니다.
+    fn skip_group_flags(&mut self) {
+        self.bump();
+        if self.rest().starts_with("P<") || self.rest().starts_with('<') {
+            // 이름이 붙은 그룹입니다. 이름은 슬롯이나 플래그와 관계가 없습니다.
+            while let Some(chr) = self.bump() {
+                if chr == '>' {
+                    break;
+                }
+            }
+            return;
+        }
+
+        let mut extended = self.extended;
+        let mut negated = false;
+        while let Some(chr) = self.peek() {
+            match chr {
+                ':' => {
+                    self.bump();
+                    self.extended = extended;
+                    return;
+                }
+                ')' => {
+                    // `(?x)`는 그룹이 아니라 현재 그룹의 플래그를 바꿉니다.
+                    self.bump();
+                    self.groups.pop();
+                    self.extended = extended;
+                    return;
+                }
+                '-' => negated = true,
+                'x' => extended = !negated,
+                chr if chr.is_ascii_alphabetic() => (),
+                // 잘못된 플래그입니다. 오류는 정규표현식 엔진이 보고합니다.
+                _ => return,
+            }
+            self.bump();
+        }
+    }
+
+    /// `[`에서 시작하는 문자 클래스를 건너뛰고 닫는 `]` 바로 뒤의 위치를 반환합니다.
+    ///
+    /// 클래스가 닫히지 않았다면 None을 반환합니다.
+    fn skip_class(&mut self) -> Option<usize> {
+        self.bump();
+        if self.peek() == Some('^') {
+            self.bump();
+        }
+        // 클래스의 맨 앞에 오는 `]`는 클래스를 닫지 않고 문자 그대로 취급됩니다.
+        if self.peek() == Some(']') {
+            self.bump();
+        } else {
+            while self.peek() == Some('-') {
+                self.bump();
+            }
+        }
+
+        loop {
+            match self.peek()? {
+                '\\' => self.skip_escape(),
+                '[' => {
+                    if !self.skip_ascii_class() {
+                        self.skip_class()?;
+                    }
+                }
+                ']' => {
+                    self.bump();
+                    return Some(self.pos);
+                }
+                '#' if self.extended => self.skip_comment(),
+                _ => {
+                    self.bump();
+                }
+            }
+        }
+    }
+
+    /// `[:alpha:]`와 같은 ASCII 클래스라면 건너뛰고 true를 반환합니다.
+    fn skip_ascii_class(&mut self) -> bool {
+        const NAMES: [&str; 14] = [
+            "alnum", "alpha", "ascii", "blank", "cntrl", "digit", "graph", "lower", "print",
+            "punct", "space", "upper", "word", "xdigit",
+        ];
+
+        let Some(inner) = self.rest().strip_prefix("[:") else {
+            return false;
+        };
+        let Some(end) = inner.find(":]") else {
+            return false;
+        };
+        let name = &inner[..end];
+        if !NAMES.contains(&name.strip_prefix('^').unwrap_or(name)) {
+            return false;
+        }
+        self.pos += "[:".len() + end + ":]".len();
+        true
+    }
+}
+
+/// `text`가 `\가`, `\J`, `\H`나 이를 부정하는 `\P{가}`, `\P{J}`, `\P{H}`로 시작한다면
+/// 나타내는 문자들과 부정 여부, 이스케이프의 바이트 길이를 반환합니다.
+///
+/// 부정은 `\P{Hangul}`과 같은 유니코드 속성의 부정을 본떴으며, 이러한 속성은 없기에 기존의 정규표현식과 겹치지 않습니다.
+fn hangul_escape(text: &str) -> Option<(HangulClass, bool, usize)> {
+    fn class(chr: char) -> Option<HangulClass> {
+        match chr {
+            '가' => Some(HangulClass::Syllable),
+            'J' => Some(HangulClass::Jamo),
+            'H' => Some(HangulClass::Hangul),
+            _ => None,
+        }
+    }
+
+    let rest = text.strip_prefix('\\')?;
+    if let Some(rest) = rest.strip_prefix("P{") {
+        let chr = rest.chars().next()?;
+        rest[chr.len_utf8()..].starts_with('}').then_some(())?;
+        return Some((class(chr)?, true, "\\P{}".len() + chr.len_utf8()));
+    }
+    let chr = rest.chars().next()?;
+    Some((class(chr)?, false, "\\".len() + chr.len_utf8()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn slots(pattern: &str) -> Vec<&str> {
+        parse(pattern, &CompileOptions::new(Order::Default))
+            .into_iter()
+            .filter_map(|piece| match piece {
+                Piece::Slot(slot) => Some(&pattern[slot.span]),
+                Piece::Literal(_) | Piece::MalformedSlot(..) | Piece::ChosungQuery(..) | Piece::HangulEscape(..) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            vec![
+                Piece::Literal("123"),
+                Piece::Slot(Slot {
+                    span: 3..26,
+                    chosungs: Part { text: "ㄱㄷㅎ", start: 4 },
+                    jungsungs: Part { text: "ㅏ", start: 14 },
+                    jongsungs: Some(Part { text: "ㅇ", start: 18 }),
+                    others: Some("은"),
+                    names: [None; 3],
+                }),
+                Piece::Literal("4"),
+            ],
+            parse("123[ㄱㄷㅎ:ㅏ:ㅇ|은]4", &CompileOptions::new(Order::Default))
+        );
+        assert_eq!(
+            vec![Piece::Slot(Slot {
+                span: 0..3,
+                chosungs: Part { text: "", start: 1 },
+                jungsungs: Part { text: "", start: 2 },
+                jongsungs: None,
+                others: None,
+                names: [None; 3],
+            })],
+            parse("[:]()", &CompileOptions::new(Order::Default))[..1]
+        );
+        assert_eq!(vec![Piece::Literal("abc")], parse("abc", &CompileOptions::new(Order::Default)));
+        assert_eq!(Vec::<Piece>::new(), parse("", &CompileOptions::new(Order::Default)));
+
+        // 슬롯 문법이 아닌 문자 클래스는 건드리지 않음
+        assert_eq!(Vec::<&str>::new(), slots("[ㄱㄷㅎ:d:ㅇ|은]"));
+        assert_eq!(Vec::<&str>::new(), slots("[ㄱ:ㄴ:ㄷ:ㄹ]"));
+        assert_eq!(vec![Piece::Literal("[a-z:]")], parse("[a-z:]", &CompileOptions::new(Order::Default)));
+        assert_eq!(
+            vec![
+                Piece::MalformedSlot("[ㄱ:d:ㅇ|은]", 0..15),
+                Piece::Literal("a"),
+            ],
+            parse("[ㄱ:d:ㅇ|은]a", &CompileOptions::new(Order::Default))
+        );
+        assert_eq!(vec!["[::|]"], slots(r"[]a][::|]]"));
+    }
+
+    #[test]
+    fn test_malformed_slot_errors() {
+        let pattern = "a[ㄱd:ㅏ:ㄴ:ㄷe|f]";
+        let spans: Vec<_> = malformed_slot_errors(pattern, 1..pattern.len(), &CompileOptions::new(Order::Default))
+            .into_iter()
+            .map(|error| &pattern[error.span()])
+            .collect();
+        assert_eq!(vec!["d", ":", "e"], spans);
+    }
+
+    #[test]
+    fn test_parse_skips_non_slot_syntax() {
+        // 이스케이프
+        assert_eq!(Vec::<&str>::new(), slots(r"\[ㄱ::]"));
+        assert_eq!(vec!["[ㄴ::]"], slots(r"\\[ㄴ::]"));
+        assert_eq!(vec!["[ㄴ::]"], slots(r"\p{Hangul}[ㄴ::]\x{41}"));
+
+        // 중첩된 문자 클래스
+        assert_eq!(Vec::<&str>::new(), slots("[a[ㄱ::]]"));
+        assert_eq!(vec!["[ㄴ::]"], slots("[[:alpha:]a[ㄱ::]][ㄴ::]"));
+        assert_eq!(vec!["[ㄴ::]"], slots(r"[\][ㄱ::]][ㄴ::]"));
+
+        // `x` 플래그의 주석
+        assert_eq!(vec!["[ㄴ::]"], slots("(?x) # [ㄱ::]\n[ㄴ::]"));
+        assert_eq!(vec!["[ㄴ::]"], slots("(?x:#[ㄱ::]\n)#[ㄴ::]"));
+        assert_eq!(vec!["[ㄴ::]"], slots("(?x)(?-x:#[ㄴ::])"));
+        assert_eq!(Vec::<&str>::new(), slots("(?ix)[# ]\n[ㄱ::]]"));
+
+        // 닫히지 않은 클래스
+        assert_eq!(vec![Piece::Literal("[ㄱ::")], parse("[ㄱ::", &CompileOptions::new(Order::Default)));
+    }
+
+    #[test]
+    fn test_parse_chosung_query() {
+        assert_eq!(
+            vec![
+                Piece::Literal("a"),
+                Piece::ChosungQuery("ㅎ국", 1..11),
+                Piece::Literal("[\\c{ㄱ}]"),
+            ],
+            parse("a\\c{ㅎ국}[\\c{ㄱ}]", &CompileOptions::new(Order::Default))
+        );
+        // 닫히지 않았다면 그대로 둠
+        assert_eq!(vec![Piece::Literal("\\c{ㄱ")], parse("\\c{ㄱ", &CompileOptions::new(Order::Default)));
+        assert_eq!(vec![Piece::Literal("\\\\c{ㄱ}")], parse("\\\\c{ㄱ}", &CompileOptions::new(Order::Default)));
+    }
+
+    #[test]
+    fn test_parse_hangul_escape() {
+        assert_eq!(
+            vec![
+                Piece::HangulEscape(HangulClass::Syllable, false, 0..4),
+                Piece::Literal("a"),
+                Piece::HangulEscape(HangulClass::Jamo, true, 5..10),
+                Piece::HangulEscape(HangulClass::Hangul, false, 10..12),
+                Piece::HangulEscape(HangulClass::Syllable, true, 12..19),
+                Piece::Literal("[\\가]"),
+            ],
+            parse("\\가a\\P{J}\\H\\P{가}[\\가]", &CompileOptions::new(Order::Default))
+        );
+        // 이스케이프된 `\`와 다른 이스케이프는 그대로 둠
+        assert_eq!(vec![Piece::Literal("\\\\가\\^H\\^J\\^가\\h")], parse("\\\\가\\^H\\^J\\^가\\h", &CompileOptions::new(Order::Default)));
+        assert_eq!(vec![Piece::Literal("\\P{Hangul}\\P{J")], parse("\\P{Hangul}\\P{J", &CompileOptions::new(Order::Default)));
+    }
+
+    #[test]
+    fn test_parse_named_sets() {
+        assert_eq!(vec!["[@경음:ㅏ]", "[:@이중모음ㅣ:^@겹받침]"], slots("[@경음:ㅏ][:@이중모음ㅣ:^@겹받침]"));
+        // `@` 뒤가 아닌 한글 음절은 슬롯에 올 수 없음
+        assert_eq!(Vec::<&str>::new(), slots("[가:나]"));
+        assert_eq!(vec![Piece::MalformedSlot("[ㄱ경:ㅏ]", 0..12)], parse("[ㄱ경:ㅏ]", &CompileOptions::new(Order::Default)));
+        assert_eq!(vec![Piece::MalformedSlot("[@:ㅏ]", 0..7)], parse("[@:ㅏ]", &CompileOptions::new(Order::Default)));
+        // 자모나 알려진 집합이 없는 클래스는 일반 문자 클래스로 남음
+        assert_eq!(vec![Piece::Literal("[@abc:]")], parse("[@abc:]", &CompileOptions::new(Order::Default)));
+        assert_eq!(vec!["[@경음::]", "[::@겹받침]"], slots("[@abc:][@경음::][::@겹받침]"));
+    }
+
+    #[test]
+    fn test_reference_tokens() {
+        assert_eq!(Some(3), reference_token_len("<a>ㄱ"));
+        assert_eq!(Some(10), reference_token_len("\\k<초성>"));
+        assert_eq!(None, reference_token_len("<>"));
+        assert_eq!(None, reference_token_len("<a"));
+        assert_eq!(None, reference_token_len("\\k<a b>"));
+        assert_eq!(vec!["[ㄱ-ㅎ<a>:ㅏ:]", "[\\k<a>::]"], slots("[ㄱ-ㅎ<a>:ㅏ:]x[\\k<a>::]"));
+        assert_eq!(Vec::<&str>::new(), slots("[<:ㅏ:]"));
+        // `\k<이름>`은 정규표현식으로 쓸 수 없기에 슬롯이 되고, 자모가 없는 클래스의 `<이름>`은 요구될 때만 슬롯이 됨
+        assert_eq!(vec!["[\\k<a>:\\k<b>]"], slots("[\\k<a>:\\k<b>]"));
+        assert_eq!(vec!["[<a>:<b>:<c>]", "[\\k<a>::]"], slots("[<a>:<b>:<c>][\\k<a>::]"));
+        assert_eq!(vec!["[<b>:]", "[\\k<b>::]"], slots("[<a>:][<b>:][\\k<b>::]"));
+        assert_eq!(vec![Piece::Literal("[<a>:][<a>::]")], parse("[<a>:][<a>::]", &CompileOptions::new(Order::Default)));
+    }
+
+    #[test]
+    fn test_is_self_contained() {
+        assert!(is_self_contained(""));
+        assert!(is_self_contained("a(?:b|c)\\(\\|[(|]"));
+        assert!(is_self_contained("(?i)a(?x:#(\n)"));
+        assert!(!is_self_contained("a|b"));
+        assert!(!is_self_contained("(a)"));
+        assert!(!is_self_contained("(?P<a>a)"));
+        assert!(!is_self_contained("(?:a"));
+        assert!(!is_self_contained("a)"));
+    }
+
+    #[test]
+    fn test_parse_slot_names() {
+        let pattern = "[(?<c>ㄱㄴ):ㅏ:(?<종성>)]";
+        let pieces = parse(pattern, &CompileOptions::new(Order::Default));
+        let slot = match &pieces[..] {
+            [Piece::Slot(slot)] => slot,
+            pieces => panic!("Should be a slot: {pieces:?}"),
+        };
+        assert_eq!([Some("c"), None, Some("종성")], slot.names);
+        assert_eq!("ㄱㄴ", &pattern[slot.chosungs.start..slot.chosungs.start + slot.chosungs.text.len()]);
+        assert_eq!(Some(""), slot.jongsungs.map(|part| part.text));
+        // 자모가 없어도 이름이 붙은 자리는 슬롯이 됨
+        assert_eq!(vec!["[(?<c>)::]", "[(?<c>):]"], slots("[(?<c>)::][(?<c>):]"));
+        // 닫히지 않은 이름
+        assert_eq!(Vec::<&str>::new(), slots("[(?<c>ㄱ:ㅏ:]"));
+    }
+}
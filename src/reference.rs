@@ -0,0 +1,403 @@
+//! 슬롯의 한 자리에 오는 자모를 이름에 묶고(`<이름>`) 뒤의 슬롯에서 같은 자모를 요구하는(`\k<이름>`) 문법을 컴파일합니다.
+//!
+//! 정규표현식 크레이트는 역참조를 지원하지 않기에, 자모를 묶은 슬롯부터 마지막으로 요구하는 슬롯까지를
+//! 묶일 수 있는 자모마다 하나씩 만들어 `(?:...|...)`로 나열합니다.
+
+use std::ops::Range;
+
+use crate::builder::CompileOptions;
+use crate::parser::{self, Part, Piece, Slot};
+use crate::substitute::sanitize_part;
+use crate::{compile_piece, KoreanRegexError, SlotKind};
+
+/// 지금까지 묶인 이름과 그 자리, 그리고 이번 나열에서 묶인 자모입니다.
+type Bindings<'a> = Vec<(&'a str, SlotKind, char)>;
+
+/// 슬롯의 한 자리에 적힌 묶기나 요구 문법입니다.
+enum Binding<'a> {
+    /// `ㄱ-ㅎ<이름>`처럼 자리의 끝에 붙어 그 자리에 오는 자모를 이름에 묶습니다. `<이름>`을 뺀 나머지를 가집니다.
+    Bind(&'a str, Part<'a>),
+    /// `\k<이름>`처럼 자리 전체를 차지하여 묶인 자모를 요구합니다.
+    Reference(&'a str),
+}
+
+/// 패턴의 조각들을 컴파일합니다. 자모를 묶거나 요구하는 문법이 있다면 나열로 풉니다.
+pub(crate) fn compile_pieces(
+    pattern: &str,
+    pieces: &[Piece],
+    options: &CompileOptions,
+) -> Result<String, Vec<KoreanRegexError>> {
+    validate(pieces)?;
+    compile_range(pattern, pieces, options, &mut Vec::new(), &mut 0)
+}
+
+/// 슬롯의 초성, 중성, 종성 자리를 순서대로 순회합니다.
+fn parts<'a>(slot: &Slot<'a>) -> impl Iterator<Item = (Part<'a>, SlotKind)> {
+    [
+        Some((slot.chosungs, SlotKind::Chosung)),
+        Some((slot.jungsungs, SlotKind::Jungsung)),
+        slot.jongsungs.map(|part| (part, SlotKind::Jongsung)),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// 슬롯의 한 자리에서 묶기나 요구 문법을 찾습니다.
+fn binding(part: Part) -> Result<Option<Binding>, KoreanRegexError> {
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while let Some(chr) = part.text[index..].chars().next() {
+        match parser::reference_token_len(&part.text[index..]) {
+            Some(length) => {
+                tokens.push(index..index + length);
+                index += length;
+            }
+            None => index += chr.len_utf8(),
+        }
+    }
+
+    let Some(token) = tokens.first().cloned() else {
+        return Ok(None);
+    };
+    let text = &part.text[token.clone()];
+    let is_reference = text.starts_with('\\');
+    let name = &text[if is_reference { "\\k<".len() } else { "<".len() }..text.len() - 1];
+    if tokens.len() == 1 && is_reference && token == (0..part.text.len()) {
+        Ok(Some(Binding::Reference(name)))
+    } else if tokens.len() == 1 && !is_reference && token.end == part.text.len() {
+        let content = Part { text: &part.text[..token.start], start: part.start };
+        Ok(Some(Binding::Bind(name, content)))
+    } else {
+        let token = if tokens.len() == 1 { token } else { tokens[1].clone() };
+        Err(KoreanRegexError::InvalidReferenceError(
+            "`<name>` must be at the end of a part and `\\k<name>` must fill a whole part.".to_string(),
+            token.start + part.start..token.end + part.start,
+        ))
+    }
+}
+
+/// 문법의 위치를 원래 패턴 기준으로 반환합니다.
+fn token_span(part: Part, name: &str, is_reference: bool) -> Range<usize> {
+    let length = name.len() + if is_reference { "\\k<>".len() } else { "<>".len() };
+    let end = part.start + part.text.len();
+    if is_reference {
+        part.start..part.start + length
+    } else {
+        end - length..end
+    }
+}
+
+/// 이름이 두 번 묶이거나, 묶이지 않은 이름을 요구하거나, 다른 자리에 묶인 이름을 요구하는지 검사합니다.
+///
+/// `(?<이름>...)`으로 슬롯의 자리에 붙인 이름이 겹치는지도 함께 검사합니다.
+fn validate(pieces: &[Piece]) -> Result<(), Vec<KoreanRegexError>> {
+    let mut bound: Vec<(&str, SlotKind)> = Vec::new();
+    let mut named: Vec<&str> = Vec::new();
+    let mut errors = Vec::new();
+    for piece in pieces {
+        let Piece::Slot(slot) = piece else {
+            continue;
+        };
+        for ((part, _), name) in parts(slot).zip(slot.names) {
+            let Some(name) = name else {
+                continue;
+            };
+            if named.contains(&name) {
+                // 이름을 여는 `(?<이름>`은 자리의 바로 앞에 있음
+                let length = name.len() + "(?<>".len();
+                errors.push(KoreanRegexError::InvalidSlotError(
+                    format!("Slot part name `{name}` is already used."),
+                    part.start - length..part.start,
+                ));
+            } else {
+                named.push(name);
+            }
+        }
+        for (part, kind) in parts(slot) {
+            match binding(part) {
+                Ok(Some(Binding::Bind(name, _))) => {
+                    if bound.iter().any(|(bound_name, _)| *bound_name == name) {
+                        errors.push(KoreanRegexError::InvalidReferenceError(
+                            format!("`<{name}>` is already bound."),
+                            token_span(part, name, false),
+                        ));
+                    } else {
+                        bound.push((name, kind));
+                    }
+                }
+                Ok(Some(Binding::Reference(name))) => {
+                    let message = match bound.iter().find(|(bound_name, _)| *bound_name == name) {
+                        None => format!("`\\k<{name}>` must come after `<{name}>`."),
+                        Some((_, bound_kind)) if *bound_kind != kind => format!(
+                            "`<{name}>` is bound to {} and cannot be used as {}.",
+                            bound_kind.name(),
+                            kind.name()
+                        ),
+                        Some(_) => continue,
+                    };
+                    errors.push(KoreanRegexError::InvalidReferenceError(message, token_span(part, name, true)));
+                }
+                Ok(None) => (),
+                Err(error) => errors.push(error),
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// 아직 묶이지 않은 첫 번째 묶기 문법을 찾습니다.
+fn unbound<'a>(piece: &Piece<'a>, bindings: &Bindings) -> Option<(&'a str, SlotKind, Part<'a>)> {
+    let Piece::Slot(slot) = piece else {
+        return None;
+    };
+    parts(slot).find_map(|(part, kind)| match binding(part) {
+        Ok(Some(Binding::Bind(name, content))) if !bindings.iter().any(|(bound, ..)| *bound == name) => {
+            Some((name, kind, content))
+        }
+        _ => None,
+    })
+}
+
+/// 조각이 주어진 이름들 중 하나를 요구하거나 묶는지 확인합니다.
+fn mentions(piece: &Piece, names: &[&str]) -> bool {
+    let Piece::Slot(slot) = piece else {
+        return false;
+    };
+    parts(slot).any(|(part, _)| match binding(part) {
+        Ok(Some(Binding::Bind(name, _) | Binding::Reference(name))) => names.contains(&name),
+        _ => false,
+    })
+}
+
+/// `start`의 조각에서 묶인 이름을 마지막으로 요구하는 조각의 위치를 찾습니다.
+///
+/// 그 사이에서 묶인 다른 이름이 더 뒤에서 요구된다면 그 위치까지 넓힙니다.
+fn region_end(pieces: &[Piece], start: usize, bindings: &Bindings) -> usize {
+    let mut end = start;
+    loop {
+        let names: Vec<&str> = pieces[start..=end]
+            .iter()
+            .filter_map(|piece| match piece {
+                Piece::Slot(slot) => Some(parts(slot).filter_map(|(part, _)| match binding(part) {
+                    Ok(Some(Binding::Bind(name, _))) if !bindings.iter().any(|(bound, ..)| *bound == name) => Some(name),
+                    _ => None,
+                })),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        let new_end = (start..pieces.len()).rev().find(|&index| mentions(&pieces[index], &names)).unwrap_or(start);
+        if new_end == end {
+            return end;
+        }
+        end = new_end;
+    }
+}
+
+/// 조각의 원래 패턴에서의 위치입니다. 문자열 조각은 위치를 가지지 않습니다.
+fn piece_span(piece: &Piece) -> Option<Range<usize>> {
+    match piece {
+        Piece::Literal(_) => None,
+        Piece::Slot(slot) => Some(slot.span.clone()),
+        Piece::MalformedSlot(_, span) | Piece::ChosungQuery(_, span) | Piece::HangulEscape(_, _, span) => {
+            Some(span.clone())
+        }
+    }
+}
+
+/// 조각들을 컴파일합니다.
+///
+/// `groups`는 지금까지 슬롯을 감싼 캡처 그룹의 수로, 나열로 같은 슬롯이 여러 번 나오더라도 그룹의 이름이 겹치지 않도록 합니다.
+fn compile_range<'a>(
+    pattern: &str,
+    pieces: &[Piece<'a>],
+    options: &CompileOptions,
+    bindings: &mut Bindings<'a>,
+    groups: &mut usize,
+) -> Result<String, Vec<KoreanRegexError>> {
+    let mut result = String::new();
+    let mut errors = Vec::new();
+    let mut index = 0;
+    while index < pieces.len() {
+        let Some((name, kind, content)) = unbound(&pieces[index], bindings) else {
+            match compile_bound_piece(pattern, &pieces[index], options, bindings, groups) {
+                Ok(compiled) => result.push_str(&compiled),
+                Err(piece_errors) => errors.extend(piece_errors),
+            }
+            index += 1;
+            continue;
+        };
+
+        let end = region_end(pieces, index, bindings);
+        let region = &pieces[index..=end];
+        let span = piece_span(&region[0]).unwrap().start..piece_span(&region[region.len() - 1]).unwrap().end;
+        let literals: String = region
+            .iter()
+            .filter_map(|piece| match piece {
+                Piece::Literal(literal) => Some(*literal),
+                _ => None,
+            })
+            .collect();
+        index = end + 1;
+        if !parser::is_self_contained(&literals) {
+            errors.push(KoreanRegexError::InvalidReferenceError(
+                "Between a binding and its references, there must be no `|` outside of groups, \
+                 unbalanced parentheses or capture groups."
+                    .to_string(),
+                span,
+            ));
+            continue;
+        }
+
+        let jamos = match sanitize_part(content, kind, options.order, options.strict, &options.named_sets) {
+            Ok(Some(jamos)) if !jamos.is_empty() => jamos,
+            Ok(_) => {
+                errors.push(KoreanRegexError::InvalidReferenceError(
+                    format!("`<{name}>` cannot bind any phoneme."),
+                    span,
+                ));
+                continue;
+            }
+            Err(part_errors) => {
+                errors.extend(part_errors);
+                continue;
+            }
+        };
+
+        let mut alternatives = Vec::with_capacity(jamos.len());
+        for jamo in jamos {
+            bindings.push((name, kind, jamo));
+            let alternative = compile_range(pattern, region, options, bindings, groups);
+            bindings.pop();
+            match alternative {
+                Ok(alternative) => alternatives.push(alternative),
+                Err(region_errors) => {
+                    errors.extend(region_errors);
+                    break;
+                }
+            }
+        }
+        result.push_str(&format!("(?:{})", alternatives.join("|")));
+    }
+
+    if errors.is_empty() {
+        Ok(result)
+    } else {
+        Err(errors)
+    }
+}
+
+/// 묶기와 요구 문법을 묶인 자모로 바꾸어 조각을 컴파일합니다.
+///
+/// options.captures가 true라면 슬롯을 [`slot_group_name`]의 캡처 그룹으로 감쌉니다.
+fn compile_bound_piece(
+    pattern: &str,
+    piece: &Piece,
+    options: &CompileOptions,
+    bindings: &Bindings,
+    groups: &mut usize,
+) -> Result<String, Vec<KoreanRegexError>> {
+    let Piece::Slot(slot) = piece else {
+        return compile_piece(pattern, piece, options);
+    };
+
+    let bound_jamo = |part: Part| match binding(part) {
+        Ok(Some(Binding::Bind(name, _) | Binding::Reference(name))) => bindings
+            .iter()
+            .find(|(bound, ..)| *bound == name)
+            .map(|(_, _, jamo)| jamo.to_string()),
+        _ => None,
+    };
+    let chosungs = bound_jamo(slot.chosungs);
+    let jungsungs = bound_jamo(slot.jungsungs);
+    let jongsungs = slot.jongsungs.and_then(bound_jamo);
+    fn resolve<'a>(part: Part<'a>, jamo: &'a Option<String>) -> Part<'a> {
+        match jamo {
+            Some(jamo) => Part { text: jamo, start: part.start },
+            None => part,
+        }
+    }
+    let start = slot.span.start;
+    let slot = Slot {
+        span: slot.span.clone(),
+        chosungs: resolve(slot.chosungs, &chosungs),
+        jungsungs: resolve(slot.jungsungs, &jungsungs),
+        jongsungs: slot.jongsungs.map(|part| resolve(part, &jongsungs)),
+        others: slot.others,
+        names: slot.names,
+    };
+    let compiled = compile_piece(pattern, &Piece::Slot(slot), options)?;
+    if !options.captures {
+        return Ok(compiled);
+    }
+    let name = slot_group_name(start, *groups);
+    *groups += 1;
+    Ok(format!("(?<{name}>{compiled})"))
+}
+
+/// 원래 패턴에서 `start`에 있는 슬롯을 감싼 `index`번째 캡처 그룹의 이름입니다.
+pub(crate) fn slot_group_name(start: usize, index: usize) -> String {
+    format!("__korean_slot_{start}_{index}")
+}
+
+/// [`slot_group_name`]으로 만든 이름이라면 슬롯의 원래 패턴에서의 위치를 반환합니다.
+pub(crate) fn slot_group_start(name: &str) -> Option<usize> {
+    let (start, _) = name.strip_prefix("__korean_slot_")?.split_once('_')?;
+    start.parse().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_bind_and_reference() {
+        let order = Order::Default;
+        assert_eq!(
+            "(?:[가-깋]x[기-깋]|[나-닣]x[니-닣])",
+            compilestr("[ㄱㄴ<a>::]x[\\k<a>:ㅣ:]", order).unwrap()
+        );
+        // 종성이 없는 경우도 묶을 수 있음
+        assert_eq!("(?:[가]a[가]|[각]a[각])", compilestr("[ㄱ:ㅏ:0ㄱ<a>]a[ㄱ:ㅏ:\\k<a>]", order).unwrap());
+        // 나열은 묶은 곳부터 마지막으로 요구하는 곳까지만 이루어짐
+        assert_eq!(
+            "a(?:[가-깋][기-깋][구-궇]|[까-낗][끼-낗][꾸-꿓])b",
+            compilestr("a[ㄱ-ㄲ<a>::][\\k<a>:ㅣ:][\\k<a>:ㅜ:]b", order).unwrap()
+        );
+
+        let regex = compile("^[<c>:<j>:<t>][\\k<c>:\\k<j>:\\k<t>]$", order).unwrap();
+        assert!(regex.is_match("하하"));
+        assert!(regex.is_match("닭닭"));
+        assert!(!regex.is_match("하호"));
+
+        let regex = compile("^[<a>::](?:[^ ]+ )*[\\k<a>::]$", order).unwrap();
+        assert!(regex.is_match("알록 달록 알"));
+        assert!(!regex.is_match("알록 달록"));
+    }
+
+    #[test]
+    fn test_reference_errors() {
+        let order = Order::Default;
+        let error = |pattern: &str| {
+            let error = compilestr(pattern, order).unwrap_err();
+            assert_eq!(ErrorKind::InvalidReference, error.kind(), "{pattern}");
+            pattern[error.span()].to_string()
+        };
+        assert_eq!("\\k<a>", error("[\\k<a>::][<a>::]"));
+        assert_eq!("<a>", error("[ㄱ<a>::][ㄴ<a>::]"));
+        assert_eq!("\\k<a>", error("[<a>::][:\\k<a>:]"));
+        assert_eq!("<a>", error("[<a>ㄱ::]"));
+        assert_eq!("\\k<a>", error("[<a>::][ㄱ\\k<a>::]"));
+        assert_eq!("[<a>::]|[\\k<a>::]", error("[<a>::]|[\\k<a>::]"));
+        assert_eq!("[<a>::](a)[\\k<a>::]", error("[<a>::](a)[\\k<a>::]"));
+        assert_eq!("[ㅏ<a>::][\\k<a>::]", error("[ㅏ<a>::][\\k<a>::]"));
+        // 요구되지 않는 `<이름>`만 있는 클래스는 일반 문자 클래스로 남음
+        assert_eq!("[<a>:]", compilestr("[<a>:]", order).unwrap());
+        // 묶기만 하고 요구하지 않아도 됨
+        assert_eq!("(?:[가]|[나])", compilestr("[ㄱㄴ<a>:ㅏ]", order).unwrap());
+    }
+}
@@ -1,6 +1,10 @@
 use std::char;
+use std::ops::Range;
 
-use crate::{CompiledOrders, KoreanRegexError, Order};
+use crate::hangul::{self, compose_jamo, Syllable};
+use crate::builder::{CompileOptions, NamedSet};
+use crate::parser::{is_set_name_char, HangulClass, Part, Slot};
+use crate::{CompiledOrders, KoreanRegexError, Order, SlotKind};
 
 /// 초성, 중성, 종성 자리에 들어갈 raw값을 받고 실제로 컴파일된 값을 내보냅니다.
 ///
@@ -12,6 +16,29 @@ use crate::{CompiledOrders, KoreanRegexError, Order};
 ///
 /// use_hyphen이 true라면 `ㄱㄴㄷㄹ`와 같은 연속된 문자열을 `ㄱ-ㄹ`과 같이 `-`을 이용한 식으로 변경하고,
 /// false라면 변경하지 않습니다.
+/// 음절을 만들 때 use_hyphen이 true라면 음절을 하나하나 나열하지 않고 코드 포인트 구간을 바로 계산하므로,
+/// 결과는 순서와 관계없이 코드 포인트 순으로 정렬된 최소한의 구간들입니다.
+/// 0.3.0 이전에는 이 경우에도 `order`의 순서대로 음절을 나열한 뒤 이어지는 부분만 `-`로 묶었기에,
+/// `Order::RegularFirst`에서는 결과 문자열이 달라졌습니다. match되는 문자들은 같습니다.
+/// use_hyphen이 false라면 이전과 같이 `order`의 순서를 따릅니다.
+///
+/// ```rust
+/// use korean_regex::*;
+///
+/// assert_eq!("가-힣", substitute("", "", "", Order::Default, true).unwrap());
+/// // 0.3.0 이전에는 `가각간갇갈감갑갓강-갛갂갃갅갆갉-갏값갔`
+/// assert_eq!("가-갛", substitute("ㄱ", "ㅏ", "", Order::RegularFirst, true).unwrap());
+/// assert!(substitute("ㄱ", "ㅏ", "", Order::RegularFirst, false).unwrap().starts_with("가각간갇"));
+/// ```
+///
+/// 오류의 위치는 세 값을 `[초성:중성:종성]`의 형태로 이어 붙인 문자열을 기준으로 합니다.
+///
+/// ```rust
+/// use korean_regex::*;
+///
+/// let error = substitute("ㄱ", "ㅏ", "ㄹ-ㄱ", Order::Default, true).unwrap_err();
+/// assert_eq!("ㄹ-ㄱ", &"[ㄱ:ㅏ:ㄹ-ㄱ]"[error.span()]);
+/// ```
 pub fn substitute<'a>(
     chosungs_raw: &'a str,
     jungsungs_raw: &'a str,
@@ -19,85 +46,516 @@ pub fn substitute<'a>(
     order: Order,
     use_hyphen: bool,
 ) -> Result<String, KoreanRegexError> {
-    let sanitize_raw_chars = |string, order| {
-        let mut unparenthesized_chars = unparenthesize(string)?;
-
-        let inverse: bool = if unparenthesized_chars.is_empty() {
-            true
-        } else if unparenthesized_chars[0] == '^' {
-            unparenthesized_chars.remove(0);
-            true
+    let chosungs = Part { text: chosungs_raw, start: 1 };
+    let jungsungs = Part { text: jungsungs_raw, start: chosungs.start + chosungs_raw.len() + 1 };
+    let jongsungs = Part { text: jongsungs_raw, start: jungsungs.start + jungsungs_raw.len() + 1 };
+    let slot = Slot {
+        span: 0..jongsungs.start + jongsungs_raw.len() + 1,
+        chosungs,
+        jungsungs,
+        jongsungs: Some(jongsungs),
+        others: None,
+        names: [None; 3],
+    };
+    substitute_slot(&slot, order, use_hyphen, false).map_err(|mut errors| errors.remove(0))
+}
+
+/// 슬롯의 초성, 중성, 종성 자리를 정리한 결과입니다. `None`은 해당 자리가 `0`이라는 의미입니다.
+type SlotParts = (Option<Vec<char>>, Option<Vec<char>>, Option<Vec<char>>);
+
+/// 패턴에서 찾은 슬롯을 정규표현식 조각으로 컴파일합니다. 오류의 위치는 원래 패턴을 기준으로 합니다.
+///
+/// 결과는 기본적으로 `[...]` 형태의 문자 클래스이며,
+/// options.conjoining이 true라면 같은 음절을 첫가끝 자모로 적은 경우도 match하는 `(?:...|...)` 형태의 묶음입니다.
+/// options.compatibility_forms가 true라면 문자 클래스에 반각 자모나 괄호, 원으로 둘러싸인 형태가 더해집니다.
+/// options.partial이 true라면 문자 클래스에 입력 중인 음절의 형태가 더해집니다.
+pub(crate) fn compile_slot(slot: &Slot, options: &CompileOptions) -> Result<String, Vec<KoreanRegexError>> {
+    let parts = sanitize_slot(slot, options.order, options.strict, &options.named_sets)?;
+    let archaic = has_archaic_syllables(&parts);
+    let sequence = if options.conjoining || archaic { conjoining_sequence(&parts) } else { None };
+    let forms: String = if options.compatibility_forms {
+        hangul::standard_forms()
+            .filter(|chr| slot_contains(&parts, *chr))
+            .flat_map(hangul::compatibility_forms)
+            .collect()
+    } else {
+        String::new()
+    };
+    let partial = if options.partial {
+        partial_syllables(&modern_parts(parts.clone()), options.use_hyphen)?
+    } else {
+        None
+    };
+    let mut substituted = if archaic {
+        // 옛한글 자모가 들어간 음절은 완성형이 없기에 첫가끝 자모의 나열로만 match함
+        let (chosungs, jungsungs, jongsungs) = modern_parts(parts);
+        combine(slot, chosungs, jungsungs, jongsungs, options.use_hyphen)?
+    } else {
+        substitute_parts(slot, parts, options.use_hyphen, options.strict)?
+    };
+    if let Some(partial) = partial {
+        substituted = partial;
+    }
+    let content = format!("{substituted}{forms}{}", slot.others.unwrap_or(""));
+    Ok(match sequence {
+        Some(sequence) if content.is_empty() => format!("(?:{sequence})"),
+        Some(sequence) => format!("(?:[{content}]|{sequence})"),
+        None => format!("[{content}]"),
+    })
+}
+
+/// 슬롯에 match되는 음절을 입력하는 도중에 나타날 수 있는 형태를 모두 포함한 문자들을 만듭니다.
+///
+/// 예를 들어 `닭`은 `ㄷ`, `다`, `달`을 거쳐 입력되고 `과`는 `ㄱ`, `고`를 거쳐 입력되기에,
+/// 슬롯에 match되는 음절에 더해 초성 하나, 종성이 아직 없는 음절, 이중 모음이나 겹받침의 앞부분만
+/// 입력된 음절을 함께 반환합니다. 된소리(`ㄲ` 등)는 한 번에 입력되기에 나누지 않습니다.
+///
+/// 음절을 만드는 슬롯이 아니라면 `None`입니다.
+fn partial_syllables(parts: &SlotParts, use_hyphen: bool) -> Result<Option<String>, Vec<KoreanRegexError>> {
+    fn with_prefixes(phonemes: &[char]) -> Vec<char> {
+        let prefixes = phonemes.iter().filter_map(|phoneme| match hangul::decompose_jamo(*phoneme) {
+            Some((first, second)) if first != second => Some(first),
+            _ => None,
+        });
+        phonemes.iter().copied().chain(prefixes).collect()
+    }
+
+    let (Some(chosungs), Some(jungsungs), jongsungs) = parts else {
+        return Ok(None);
+    };
+    let jongsungs = jongsungs.clone().unwrap_or_else(|| vec!['0']);
+    let (all_chosungs, all_jungsungs, all_jongsungs_with_zero) = Order::Default.order();
+    let chosung_positions = default_positions(chosungs, all_chosungs, SlotKind::Chosung)?;
+    let jungsung_positions = default_positions(jungsungs, all_jungsungs, SlotKind::Jungsung)?;
+    let jongsung_positions = default_positions(&jongsungs, all_jongsungs_with_zero, SlotKind::Jongsung)?;
+    let growing_jungsungs = default_positions(&with_prefixes(jungsungs), all_jungsungs, SlotKind::Jungsung)?;
+    let mut growing_jongsungs = with_prefixes(&jongsungs);
+    growing_jongsungs.push('0');
+    let growing_jongsungs = default_positions(&growing_jongsungs, all_jongsungs_with_zero, SlotKind::Jongsung)?;
+
+    let mut ranges = syllable_ranges(&chosung_positions, &jungsung_positions, &jongsung_positions);
+    ranges.extend(syllable_ranges(&chosung_positions, &growing_jungsungs, &[0]));
+    ranges.extend(syllable_ranges(&chosung_positions, &jungsung_positions, &growing_jongsungs));
+    ranges.sort_unstable();
+    let mut merged: Vec<(u32, u32)> = Vec::new();
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some((_, last_end)) if start <= *last_end + 1 => *last_end = end.max(*last_end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    let mut result = if use_hyphen {
+        render_ranges(&merged)
+    } else {
+        merged
+            .into_iter()
+            .flat_map(|(start, end)| (start..=end).filter_map(char::from_u32))
+            .collect()
+    };
+    result.extend(chosungs.iter());
+    Ok(Some(result))
+}
+
+/// 음절을 만드는 슬롯에 옛한글 자모가 들어 있는지 확인합니다.
+fn has_archaic_syllables(parts: &SlotParts) -> bool {
+    let (all_chosungs, all_jungsungs, all_jongsungs_with_zero) = Order::Default.order();
+    match parts {
+        (Some(chosungs), Some(jungsungs), jongsungs) => {
+            chosungs.iter().any(|chr| !all_chosungs.contains(chr))
+                || jungsungs.iter().any(|chr| !all_jungsungs.contains(chr))
+                || jongsungs.iter().flatten().any(|chr| !all_jongsungs_with_zero.contains(chr))
+        }
+        _ => false,
+    }
+}
+
+/// 슬롯의 각 자리에서 옛한글 자모를 뺍니다.
+fn modern_parts((chosungs, jungsungs, jongsungs): SlotParts) -> SlotParts {
+    let (all_chosungs, all_jungsungs, all_jongsungs_with_zero) = Order::Default.order();
+    let retain = |phonemes: Option<Vec<char>>, all_phonemes: &[char]| {
+        phonemes.map(|phonemes| phonemes.into_iter().filter(|chr| all_phonemes.contains(chr)).collect())
+    };
+    (
+        retain(chosungs, all_chosungs),
+        retain(jungsungs, all_jungsungs),
+        retain(jongsungs, all_jongsungs_with_zero),
+    )
+}
+
+/// `\c{ㅎ국}`과 같은 초성 검색 문법의 내용을 컴파일합니다. `span`은 원래 패턴에서 `\c{`부터 `}`까지의 위치입니다.
+///
+/// 초성이 될 수 있는 자음은 `[ㅎ::]`과 같은 슬롯으로 바뀌어 해당 자음이 초성인 모든 음절에 match하고,
+/// 그 외의 문자는 문자 그대로 match합니다. 공백은 `x` 모드에서도 사라지지 않도록 `\x20`처럼 적습니다.
+/// 엄격 모드에서는 초성이 될 수 없는 자모(`ㄳ`, `ㅏ` 등)를 오류로 취급하며, 비어 있는 `\c{}`는 항상 오류입니다.
+pub(crate) fn compile_chosung_query(
+    text: &str,
+    span: Range<usize>,
+    options: &CompileOptions,
+) -> Result<String, Vec<KoreanRegexError>> {
+    if text.is_empty() {
+        return Err(vec![KoreanRegexError::InvalidSlotError(
+            "Chosung query cannot be empty.".to_string(),
+            span,
+        )]);
+    }
+    let start = span.start + "\\c{".len();
+    let (all_chosungs, ..) = options.order.order();
+    let mut result = String::new();
+    let mut errors = Vec::new();
+    for (index, chr) in text.char_indices() {
+        let offset = start + index;
+        let end = offset + chr.len_utf8();
+        if all_chosungs.contains(&chr) {
+            let slot = Slot {
+                span: offset..end,
+                chosungs: Part { text: &text[index..index + chr.len_utf8()], start: offset },
+                jungsungs: Part { text: "", start: end },
+                jongsungs: Some(Part { text: "", start: end }),
+                others: None,
+                names: [None; 3],
+            };
+            match compile_slot(&slot, options) {
+                Ok(compiled) => result.push_str(&compiled),
+                Err(slot_errors) => errors.extend(slot_errors),
+            }
+        } else if options.strict && matches!(chr, 'ㄱ'..='ㅣ' | '\u{3165}'..='\u{318E}') {
+            errors.push(KoreanRegexError::InvalidPhonemeError(
+                format!("Character `{chr}` cannot be used as chosung."),
+                chr,
+                Some(SlotKind::Chosung),
+                offset..end,
+            ));
+        } else if chr.is_whitespace() {
+            result.push_str(&format!("\\x{{{:X}}}", chr as u32));
         } else {
-            false
+            result.push_str(&regex::escape(chr.encode_utf8(&mut [0; 4])));
+        }
+    }
+    if errors.is_empty() {
+        Ok(result)
+    } else {
+        Err(errors)
+    }
+}
+
+/// `\가`, `\J`, `\H`와 같은 한글 축약 이스케이프를 컴파일합니다. `span`은 원래 패턴에서 이스케이프의 위치입니다.
+///
+/// `\가`는 `[::]` 슬롯과 똑같이 컴파일되기에 옵션에 따라 첫가끝 자모로 적힌 음절이나 다른 형태에도 match하며,
+/// `\J`는 호환용 자모에, `\H`는 그 둘 모두에 match합니다.
+/// `\P{가}`처럼 부정된 이스케이프는 문자 클래스의 부정이기에 완성형 음절과 호환용 자모만을 기준으로 한 글자에 match합니다.
+pub(crate) fn compile_hangul_escape(
+    class: HangulClass,
+    negated: bool,
+    span: Range<usize>,
+    options: &CompileOptions,
+) -> Result<String, Vec<KoreanRegexError>> {
+    let any_syllable = |options: &CompileOptions| {
+        let empty = Part { text: "", start: span.end };
+        let slot = Slot {
+            span: span.clone(),
+            chosungs: empty,
+            jungsungs: empty,
+            jongsungs: Some(empty),
+            others: None,
+            names: [None; 3],
         };
+        compile_slot(&slot, options)
+    };
+    let jamos = jamo_class(options);
+
+    if negated {
+        let mut modern = options.clone();
+        if modern.order == Order::OldHangul {
+            modern.order = Order::Default;
+        }
+        modern.conjoining = false;
+        modern.partial = false;
+        let syllables = any_syllable(&modern)?;
+        let syllables = &syllables[1..syllables.len() - 1];
+        return Ok(match class {
+            HangulClass::Syllable => format!("[^{syllables}]"),
+            HangulClass::Jamo => format!("[^{jamos}]"),
+            HangulClass::Hangul => format!("[^{syllables}{jamos}]"),
+        });
+    }
 
-        Ok(sanitize(unparenthesized_chars, order, inverse))
+    Ok(match class {
+        HangulClass::Syllable => any_syllable(options)?,
+        HangulClass::Jamo => format!("[{jamos}]"),
+        HangulClass::Hangul => {
+            let syllables = any_syllable(options)?;
+            match syllables.strip_prefix('[').and_then(|syllables| syllables.strip_suffix(']')) {
+                Some(syllables) => format!("[{syllables}{jamos}]"),
+                None => format!("(?:{syllables}|[{jamos}])"),
+            }
+        }
+    })
+}
+
+/// `\J`가 나타내는 호환용 자모들을 문자 클래스 안에 들어갈 문자열로 만듭니다.
+///
+/// 옛한글 순서에서는 옛한글 호환용 자모도 포함합니다.
+fn jamo_class(options: &CompileOptions) -> String {
+    let mut codes: Vec<u32> = ('ㄱ'..='ㅣ').map(|chr| chr as u32).collect();
+    if options.order == Order::OldHangul {
+        codes.extend(hangul::ARCHAIC_JAMO.iter().map(|(chr, ..)| *chr as u32));
+        codes.sort_unstable();
+    }
+    let mut result = if options.use_hyphen {
+        render_ranges(&runs(&codes))
+    } else {
+        codes.iter().filter_map(|code| char::from_u32(*code)).collect()
     };
+    if options.compatibility_forms {
+        result.extend(
+            hangul::standard_forms()
+                .filter(|chr| codes.contains(&(*chr as u32)))
+                .flat_map(hangul::compatibility_forms),
+        );
+    }
+    result
+}
+
+/// 패턴에서 찾은 슬롯을 컴파일합니다. 오류의 위치는 원래 패턴을 기준으로 합니다.
+///
+/// strict가 true라면 해당 자리에 올 수 없어 무시되던 문자와
+/// 어떤 문자에도 match할 수 없는 슬롯을 오류로 취급합니다.
+fn substitute_slot(
+    slot: &Slot,
+    order: Order,
+    use_hyphen: bool,
+    strict: bool,
+) -> Result<String, Vec<KoreanRegexError>> {
+    let parts = sanitize_slot(slot, order, strict, &[])?;
+    substitute_parts(slot, parts, use_hyphen, strict)
+}
+
+fn substitute_parts(
+    slot: &Slot,
+    (chosungs, jungsungs, jongsungs): SlotParts,
+    use_hyphen: bool,
+    strict: bool,
+) -> Result<String, Vec<KoreanRegexError>> {
+    let substituted = combine(slot, chosungs, jungsungs, jongsungs, use_hyphen)?;
+    if strict && substituted.is_empty() {
+        return Err(vec![KoreanRegexError::InvalidSlotError(
+            "Slot cannot match any character.".to_string(),
+            slot.span.clone(),
+        )]);
+    }
+    Ok(substituted)
+}
+
+/// 슬롯의 초성, 중성, 종성 자리를 각각 해당 자리에 올 수 있는 문자들로 정리합니다.
+///
+/// 초성, 중성, 종성 자리는 서로 독립적으로 검사되기에 각 자리에서 난 오류를 모두 반환합니다.
+///
+/// `@이름`은 `named_sets`에 등록된 집합을 먼저 찾은 뒤 기본 집합을 찾습니다.
+fn sanitize_slot(
+    slot: &Slot,
+    order: Order,
+    strict: bool,
+    named_sets: &[NamedSet],
+) -> Result<SlotParts, Vec<KoreanRegexError>> {
+    let mut errors = Vec::new();
+    let mut sanitize_kind = |part: Part, kind: SlotKind| match sanitize_part(part, kind, order, strict, named_sets) {
+        Ok(chars) => chars,
+        Err(part_errors) => {
+            errors.extend(part_errors);
+            None
+        }
+    };
+
+    let chosungs = sanitize_kind(slot.chosungs, SlotKind::Chosung);
+    let jungsungs = sanitize_kind(slot.jungsungs, SlotKind::Jungsung);
+    let jongsungs = match slot.jongsungs {
+        Some(jongsungs) => sanitize_kind(jongsungs, SlotKind::Jongsung),
+        None => None,
+    };
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    Ok((chosungs, jungsungs, jongsungs))
+}
+
+/// 슬롯의 한 자리를 해당 자리에 올 수 있는 문자들로 정리합니다. `None`은 해당 자리가 `0`이라는 의미입니다.
+///
+/// 엄격 모드에서는 해당 자리에 올 수 없는 문자들의 오류를 모두 반환합니다.
+pub(crate) fn sanitize_part(
+    part: Part,
+    kind: SlotKind,
+    order: Order,
+    strict: bool,
+    named_sets: &[NamedSet],
+) -> Result<Option<Vec<char>>, Vec<KoreanRegexError>> {
+    if part.text == "0" {
+        return Ok(None);
+    }
 
     let (all_chosungs, all_jungsungs, all_jongsungs_with_zero) = order.order();
-    let chosungs = if chosungs_raw == "0" {
-        None
-    } else {
-        Some(sanitize_raw_chars(chosungs_raw, all_chosungs)??)
+    let order = match kind {
+        SlotKind::Chosung => all_chosungs,
+        SlotKind::Jungsung => all_jungsungs,
+        SlotKind::Jongsung => all_jongsungs_with_zero,
     };
-    let jungsungs = if jungsungs_raw == "0" {
-        None
+    let in_part = |error: KoreanRegexError| vec![error.offset(part.start).in_slot(kind)];
+
+    let unparenthesized_chars = unparenthesize(part.text).map_err(in_part)?;
+    let mut unparenthesized_chars = expand_named_sets(unparenthesized_chars, order, kind, named_sets).map_err(in_part)?;
+
+    let inverse: bool = if unparenthesized_chars.is_empty() {
+        true
+    } else if unparenthesized_chars[0].0 == '^' {
+        unparenthesized_chars.remove(0);
+        true
     } else {
-        Some(sanitize_raw_chars(jungsungs_raw, all_jungsungs)??)
+        false
     };
-    let jongsungs = if jongsungs_raw == "0" {
-        None
-    } else {
-        Some(sanitize_raw_chars(
-            jongsungs_raw,
-            all_jongsungs_with_zero,
-        )??)
+
+    if strict {
+        let errors: Vec<_> = unparenthesized_chars
+            .iter()
+            .filter(|(chr, _)| *chr != '-' && !order.contains(chr))
+            .map(|(chr, span)| {
+                KoreanRegexError::InvalidPhonemeError(
+                    format!("Character `{chr}` cannot be used as {}.", kind.name()),
+                    *chr,
+                    Some(kind),
+                    span.start + part.start..span.end + part.start,
+                )
+            })
+            .collect();
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+    }
+
+    sanitize(unparenthesized_chars, order, inverse).map(Some).map_err(in_part)
+}
+
+/// 호환용 자모나 완성형 음절 하나가 정리된 슬롯에 match되는지 확인합니다.
+fn slot_contains((chosungs, jungsungs, jongsungs): &SlotParts, chr: char) -> bool {
+    let contains = |phonemes: &Option<Vec<char>>, phoneme: char| {
+        phonemes.as_ref().is_some_and(|phonemes| phonemes.contains(&phoneme))
     };
+    match Syllable::from_char(chr) {
+        Some(syllable) => {
+            let jongsung = syllable.jongsung().unwrap_or('0');
+            contains(chosungs, syllable.chosung())
+                && contains(jungsungs, syllable.jungsung())
+                && match jongsungs {
+                    Some(jongsungs) => jongsungs.contains(&jongsung),
+                    None => jongsung == '0',
+                }
+        }
+        None => match (chosungs, jungsungs, jongsungs) {
+            (Some(chars), None, None) | (None, Some(chars), None) | (None, None, Some(chars)) => chars.contains(&chr),
+            _ => false,
+        },
+    }
+}
+
+/// 슬롯에 match되는 음절을 첫가끝 자모(U+1100–U+11FF)로 적은 문자열에 match하는 정규표현식을 만듭니다.
+///
+/// 예를 들어 `[ㄱ:ㅏ:0ㄴ]`은 `[ᄀ][ᅡ][ᆫ]?`이 됩니다. 음절을 만들지 않는 슬롯이라면 `None`입니다.
+///
+/// 정규표현식 크레이트가 전방 탐색을 지원하지 않기에 종성이 없는 음절은
+/// 첫가끝 자모로 적힌 음절의 초성과 중성에도 match합니다.
+fn conjoining_sequence((chosungs, jungsungs, jongsungs): &SlotParts) -> Option<String> {
+    fn class(phonemes: &[char], kind: SlotKind) -> Option<String> {
+        let mut codes: Vec<u32> = phonemes
+            .iter()
+            .filter_map(|phoneme| hangul::conjoining_jamo(*phoneme, kind))
+            .map(|chr| chr as u32)
+            .collect();
+        codes.sort_unstable();
+        codes.dedup();
+        (!codes.is_empty()).then(|| format!("[{}]", render_ranges(&runs(&codes))))
+    }
+
+    let mut sequence = class(chosungs.as_ref()?, SlotKind::Chosung)?;
+    sequence.push_str(&class(jungsungs.as_ref()?, SlotKind::Jungsung)?);
+    if let Some(jongsungs) = jongsungs {
+        if let Some(jongsung_class) = class(jongsungs, SlotKind::Jongsung) {
+            sequence.push_str(&jongsung_class);
+            if jongsungs.contains(&'0') {
+                sequence.push('?');
+            }
+        }
+    }
+    Some(sequence)
+}
+
+/// 정리된 초성, 중성, 종성을 조합해 슬롯에 match되는 문자들을 만듭니다.
+fn combine(
+    slot: &Slot,
+    chosungs: Option<Vec<char>>,
+    jungsungs: Option<Vec<char>>,
+    jongsungs: Option<Vec<char>>,
+    use_hyphen: bool,
+) -> Result<String, Vec<KoreanRegexError>> {
 
     let regular_compiled_order = Order::Default.order();
 
     match (chosungs, jungsungs, jongsungs) {
         (None, None, None) =>
-            Err(KoreanRegexError::InvalidZeroPatternError("[0:0:0] cannot be represented as Hangeul, thus invalid.".to_string())),
+            Err(vec![KoreanRegexError::InvalidZeroPatternError(
+                "[0:0:0] cannot be represented as Hangeul, thus invalid.".to_string(),
+                slot.span.clone(),
+            )]),
         (None, Some(jungsungs), Some(jongsungs)) =>
-            Err(KoreanRegexError::InvalidZeroPatternError(
+            Err(vec![KoreanRegexError::InvalidZeroPatternError(
                 format!("[0:{}:{}]([0:*:*] pattern) cannot be represented as Hangeul, thus invalid.",
                     jungsungs.into_iter().collect::<String>(),
                     jongsungs.into_iter().collect::<String>(),
                 ),
-            )),
+                slot.span.clone(),
+            )]),
         (Some(chosungs), None, Some(jongsungs)) =>
-            Err(KoreanRegexError::InvalidZeroPatternError(
+            Err(vec![KoreanRegexError::InvalidZeroPatternError(
                     format!(
                         "[{}:0:{}]([*:0:*] pattern) cannot be represented as Hangeul, thus invalid.",
                         chosungs.into_iter().collect::<String>(),
                         jongsungs.into_iter().collect::<String>(),
                     ),
-                )),
+                    slot.span.clone(),
+                )]),
         (Some(chars), None, None)
         | (None, Some(chars), None)
         | (None, None, Some(chars)) => Ok(chars.into_iter().collect()),
+        (Some(chosungs), Some(jungsungs), Some(jongsungs)) if use_hyphen => {
+            let chosungs = default_positions(&chosungs, regular_compiled_order.0, SlotKind::Chosung)?;
+            let jungsungs = default_positions(&jungsungs, regular_compiled_order.1, SlotKind::Jungsung)?;
+            let jongsungs = default_positions(&jongsungs, regular_compiled_order.2, SlotKind::Jongsung)?;
+            Ok(render_ranges(&syllable_ranges(&chosungs, &jungsungs, &jongsungs)))
+        },
+        (Some(chosungs), Some(jungsungs), None) if use_hyphen => {
+            let chosungs = default_positions(&chosungs, regular_compiled_order.0, SlotKind::Chosung)?;
+            let jungsungs = default_positions(&jungsungs, regular_compiled_order.1, SlotKind::Jungsung)?;
+            Ok(render_ranges(&syllable_ranges(&chosungs, &jungsungs, &[0])))
+        },
         (Some(chosungs), Some(jungsungs), Some(jongsungs)) => {
             let mut result = String::new();
             for chosung in chosungs.iter() {
                 for jungsung in jungsungs.iter() {
                     for jongsung in jongsungs.iter() {
                         result.push(convert_phonemes_to_syllable(
-                            *chosung, *jungsung, Some(*jongsung), regular_compiled_order)?);
+                            *chosung, *jungsung, Some(*jongsung), regular_compiled_order).map_err(|error| vec![error])?);
                     }
                 }
             }
-            Ok(if use_hyphen { replace_with_hyphen(result) } else { result })
+            Ok(result)
         },
         (Some(first), Some(middle), None) => {
             let mut result = String::new();
             for chosung in first.iter() {
                 for jungsung in middle.iter() {
                     result.push(convert_phonemes_to_syllable(
-                        *chosung, *jungsung, None, regular_compiled_order)?);
+                        *chosung, *jungsung, None, regular_compiled_order).map_err(|error| vec![error])?);
                 }
             }
-            Ok(if use_hyphen { replace_with_hyphen(result) } else { result })
+            Ok(result)
         },
     }
 }
@@ -106,66 +564,58 @@ pub fn substitute<'a>(
 ///
 /// 예를 들어 `ㅢ`의 경우 `(ㅡㅣ)`로 표시할 수 있고, `ㄼ`의 경우 `ㄹㅂ`으로 표시할 수 있습니다.
 /// 이는 글자 입력기가 조합을 지원하지 않는 경우 유용하게 사용할 수 있습니다.
+/// 사용할 수 있는 조합은 [`hangul::COMPOUND_JAMO`](crate::hangul::COMPOUND_JAMO)에 있습니다.
 ///
 /// ```rust
 /// use korean_regex::*;
 /// assert_eq!("[깕깗끩끫낅낇딹딻띍띏띩띫]",
 ///            compilestr("[(ㄱㄱ)ㄸ:ㅏㅣ(ㅡㅣ):(ㄹㅂ)ㄺ]", Order::Default).unwrap())
 /// ```
-fn unparenthesize(parenthesized_string: &str) -> Result<Vec<char>, KoreanRegexError> {
+///
+/// 결과의 각 문자는 `parenthesized_string` 안에서 해당 문자가 적힌 위치를 함께 가집니다.
+fn unparenthesize(parenthesized_string: &str) -> Result<Vec<(char, Range<usize>)>, KoreanRegexError> {
     let mut does_inside_parenthisis = false;
+    let mut parenthesis_start = 0;
     let mut chars_inside_parenthesis = String::with_capacity(2);
     let mut unparenthesized_chars = Vec::with_capacity(parenthesized_string.len());
-    for char in parenthesized_string.chars() {
+    for (index, char) in parenthesized_string.char_indices() {
         match char {
             '(' => {
                 if does_inside_parenthisis {
                     return Err(KoreanRegexError::UnparenthesizingFailedError(
                         "Invalid Syntax: Open parenthesis after another open parenthesis."
                             .to_string(),
+                        None,
+                        index..index + 1,
                     ));
                 } else {
-                    does_inside_parenthisis = true
+                    does_inside_parenthisis = true;
+                    parenthesis_start = index;
                 }
             }
             ')' => {
                 if does_inside_parenthisis {
                     does_inside_parenthisis = false;
-                    let converted_char = match chars_inside_parenthesis.as_str() {
-                        "ㅗㅏ" => 'ㅘ',
-                        "ㅗㅐ" => 'ㅙ',
-                        "ㅗㅣ" => 'ㅚ',
-                        "ㅜㅓ" => 'ㅝ',
-                        "ㅜㅔ" => 'ㅞ',
-                        "ㅜㅣ" => 'ㅟ',
-                        "ㅡㅣ" => 'ㅢ',
-                        "ㄱㅅ" => 'ㄳ',
-                        "ㄴㅈ" => 'ㄵ',
-                        "ㄴㅎ" => 'ㄶ',
-                        "ㄹㄱ" => 'ㄺ',
-                        "ㄹㅁ" => 'ㄻ',
-                        "ㄹㅂ" => 'ㄼ',
-                        "ㄹㅅ" => 'ㄽ',
-                        "ㄹㅌ" => 'ㄾ',
-                        "ㄹㅎ" => 'ㅀ',
-                        "ㅂㅅ" => 'ㅄ',
-                        "ㄱㄱ" => 'ㄲ',
-                        "ㄷㄷ" => 'ㄸ',
-                        "ㅈㅈ" => 'ㅉ',
-                        "ㅂㅂ" => 'ㅃ',
-                        other => {
-                            return Err(KoreanRegexError::UnparenthesizingFailedError(format!(
-                                "Invalid Syntax: Unknown item inside parenthesis({}).",
-                                other
-                            )));
-                        }
+                    let mut jamos = chars_inside_parenthesis.chars();
+                    let converted_char = match (jamos.next(), jamos.next(), jamos.next()) {
+                        (Some(first), Some(second), None) => compose_jamo(first, second),
+                        _ => None,
+                    };
+                    let Some(converted_char) = converted_char else {
+                        return Err(KoreanRegexError::UnparenthesizingFailedError(
+                            format!("Invalid Syntax: Unknown item inside parenthesis({}).", chars_inside_parenthesis),
+                            None,
+                            parenthesis_start..index + 1,
+                        ));
                     };
                     chars_inside_parenthesis.clear();
-                    unparenthesized_chars.push(converted_char);
+                    unparenthesized_chars.push((converted_char, parenthesis_start..index + 1));
                 } else {
                     return Err(KoreanRegexError::UnparenthesizingFailedError(
                         "Invalid Syntax: Close parenthesis after another close parenthesis."
                             .to_string(),
+                        None,
+                        index..index + 1,
                     ));
                 }
             }
@@ -173,14 +623,120 @@ fn unparenthesize(parenthesized_string: &str) -> Result<Vec<char>, KoreanRegexEr
                 if does_inside_parenthisis {
                     chars_inside_parenthesis.push(others)
                 } else {
-                    unparenthesized_chars.push(others)
+                    unparenthesized_chars.push((others, index..index + others.len_utf8()))
                 }
             }
         }
     }
+    if does_inside_parenthisis {
+        return Err(KoreanRegexError::UnparenthesizingFailedError(
+            "Invalid Syntax: Parenthesis is not closed.".to_string(),
+            None,
+            parenthesis_start..parenthesized_string.len(),
+        ));
+    }
     Ok(unparenthesized_chars)
 }
 
+/// `@경음`과 같이 이름이 붙은 자모 집합을 그 집합에 속하면서 `order`에 있는 자모들로 풉니다.
+///
+/// 풀린 자모들은 모두 `@`부터 이름의 끝까지를 위치로 가집니다.
+/// 집합은 하이픈 범위의 양 끝이 될 수 없습니다.
+///
+/// ```rust
+/// use korean_regex::*;
+///
+/// let order = Order::Default;
+/// assert_eq!("[까따빠싸짜]", compilestr("[@경음:ㅏ]", order).unwrap());
+/// assert_eq!("[갂갔]", compilestr("[ㄱ:ㅏ:@경음]", order).unwrap());
+/// assert_eq!("[가까따빠싸짜]", compilestr("[ㄱ@경음:ㅏ]", order).unwrap());
+/// assert_eq!("[ㅏㅐㅓㅔㅗㅚㅜㅟㅡㅣ]", compilestr("[0:^@이중모음:0]", order).unwrap());
+/// ```
+fn expand_named_sets(
+    chars: Vec<(char, Range<usize>)>,
+    order: &[char],
+    kind: SlotKind,
+    named_sets: &[NamedSet],
+) -> Result<Vec<(char, Range<usize>)>, KoreanRegexError> {
+    let mut expanded = Vec::with_capacity(chars.len());
+    let mut index = 0;
+    while index < chars.len() {
+        if chars[index].0 != '@' {
+            expanded.push(chars[index].clone());
+            index += 1;
+            continue;
+        }
+
+        let name_end = chars[index + 1..]
+            .iter()
+            .position(|(chr, _)| !is_set_name_char(*chr))
+            .map_or(chars.len(), |length| index + 1 + length);
+        let name: String = chars[index + 1..name_end].iter().map(|(chr, _)| chr).collect();
+        let span = chars[index].1.start..chars[name_end - 1].1.end;
+
+        let next_to_hyphen = (index > 0 && chars[index - 1].0 == '-')
+            || chars.get(name_end).is_some_and(|(chr, _)| *chr == '-');
+        if next_to_hyphen {
+            return Err(KoreanRegexError::InvalidHyphenError(
+                format!("Named set `@{name}` cannot be an end of hyphen range."),
+                None,
+                span,
+            ));
+        }
+
+        let registered = named_sets.iter().find(|set| set.name == name && set.kind == kind);
+        let Some(set) = registered.map(|set| &set.jamos[..]).or_else(|| hangul::named_jamo_set(&name)) else {
+            let message = if named_sets.iter().any(|set| set.name == name) {
+                format!("Named set `@{name}` is not registered for {}.", kind.name())
+            } else {
+                format!("Unknown named set `@{name}`.")
+            };
+            return Err(KoreanRegexError::InvalidSetError(message, None, span));
+        };
+        let count = expanded.len();
+        expanded.extend(set.iter().filter(|chr| order.contains(chr)).map(|&chr| (chr, span.clone())));
+        if expanded.len() == count {
+            return Err(KoreanRegexError::InvalidSetError(
+                format!("Named set `@{name}` has no phoneme that can be used as {}.", kind.name()),
+                None,
+                span,
+            ));
+        }
+        index = name_end;
+    }
+    Ok(expanded)
+}
+
+/// 빌더에 등록할 자모 집합을 슬롯의 한 자리와 같은 문법으로 읽어 순서대로 정리합니다.
+///
+/// 모든 자모는 `order`에서 `kind` 자리에 올 수 있어야 하며, 오류의 위치는 `jamos`를 기준으로 합니다.
+pub(crate) fn sanitize_named_set(jamos: &str, order: Order, kind: SlotKind) -> Result<Vec<char>, KoreanRegexError> {
+    let (all_chosungs, all_jungsungs, all_jongsungs_with_zero) = order.order();
+    let table = match kind {
+        SlotKind::Chosung => all_chosungs,
+        SlotKind::Jungsung => all_jungsungs,
+        SlotKind::Jongsung => all_jongsungs_with_zero,
+    };
+    let chars = unparenthesize(jamos).map_err(|error| error.in_slot(kind))?;
+    if let Some((chr, span)) = chars.iter().find(|(chr, _)| *chr != '-' && (*chr == '0' || !table.contains(chr))) {
+        return Err(KoreanRegexError::InvalidPhonemeError(
+            format!("Character `{chr}` cannot be used as {}.", kind.name()),
+            *chr,
+            Some(kind),
+            span.clone(),
+        ));
+    }
+    let sanitized = sanitize(chars, table, false).map_err(|error| error.in_slot(kind))?;
+    if sanitized.is_empty() {
+        return Err(KoreanRegexError::InvalidSetError(
+            "Named set cannot be empty.".to_string(),
+            Some(kind),
+            0..jamos.len(),
+        ));
+    }
+    Ok(sanitized)
+}
+
 /// 이 함수는 다음과 같은 일을 합니다.
 ///
 /// 1. hyphen이 이용된 경우 풀어 씁니다.
@@ -188,34 +744,42 @@ fn unparenthesize(parenthesized_string: &str) -> Result<Vec<char>, KoreanRegexEr
 /// 1. 같은 글자가 있을 경우 중복을 제거합니다.
 /// 1. 만약 inverse=True일 경우 결과값을 뒤집습니다.
 fn sanitize(
-    unsanitized_chars: Vec<char>,
+    unsanitized_chars: Vec<(char, Range<usize>)>,
     order: &[char],
     inverse: bool,
 ) -> Result<Vec<char>, KoreanRegexError> {
     fn add_chars_in_range(
         char_present_table: &mut [bool],
-        char_before_hyphen: char,
-        char_after_hyphen: char,
+        (char_before_hyphen, span_before_hyphen): &(char, Range<usize>),
+        (char_after_hyphen, span_after_hyphen): &(char, Range<usize>),
         order: &[char],
     ) -> Result<(), KoreanRegexError> {
-        let before_letter_index = order.iter().position(|&r| r == char_before_hyphen).ok_or(
+        let before_letter_index = order.iter().position(|r| r == char_before_hyphen).ok_or(
             KoreanRegexError::InvalidPhonemeError(
                 format!("Charactor `{}` is not valid phoneme.", char_before_hyphen),
-                char_before_hyphen,
+                *char_before_hyphen,
+                None,
+                span_before_hyphen.clone(),
             ),
         )?;
-        let after_letter_index = order.iter().position(|&r| r == char_after_hyphen).ok_or(
+        let after_letter_index = order.iter().position(|r| r == char_after_hyphen).ok_or(
             KoreanRegexError::InvalidPhonemeError(
                 format!("Charactor `{}` is not valid phoneme.", char_after_hyphen),
-                char_before_hyphen,
+                *char_after_hyphen,
+                None,
+                span_after_hyphen.clone(),
             ),
         )?;
 
         if before_letter_index > after_letter_index {
-            return Err(KoreanRegexError::InvalidHyphenError(format!(
-                "The charactor before hyphen({char_before_hyphen}) is bigger than\
+            return Err(KoreanRegexError::InvalidHyphenError(
+                format!(
+                    "The charactor before hyphen({char_before_hyphen}) is bigger than\
                          the charactor after it({char_after_hyphen})."
-            )));
+                ),
+                None,
+                span_before_hyphen.start..span_after_hyphen.end,
+            ));
         }
 
         #[allow(clippy::needless_range_loop)]
@@ -226,30 +790,24 @@ fn sanitize(
     }
 
     let mut char_present_table = vec![false; order.len()];
-    if !unsanitized_chars.is_empty() {
-        for (char_index, unsanitized_char) in unsanitized_chars.iter().enumerate() {
-            for (order_index, char_in_order) in order.iter().enumerate() {
-                if unsanitized_char == &'-' {
-                    if char_index >= unsanitized_chars.len() - 1 || char_index == 0 {
-                        return Err(KoreanRegexError::InvalidHyphenError(
-                            "Position of hyphen is invalid.".to_string(),
-                        ));
-                    }
-
-                    let char_before_hyphen = unsanitized_chars[char_index - 1];
-                    let char_after_hyphen = unsanitized_chars[char_index + 1];
-
-                    add_chars_in_range(
-                        &mut char_present_table,
-                        char_before_hyphen,
-                        char_after_hyphen,
-                        order,
-                    )?;
-                } else if unsanitized_char == char_in_order {
-                    char_present_table[order_index] = true;
-                    break;
-                }
+    for (char_index, (unsanitized_char, span)) in unsanitized_chars.iter().enumerate() {
+        if *unsanitized_char == '-' {
+            if char_index >= unsanitized_chars.len() - 1 || char_index == 0 {
+                return Err(KoreanRegexError::InvalidHyphenError(
+                    "Position of hyphen is invalid.".to_string(),
+                    None,
+                    span.clone(),
+                ));
             }
+
+            add_chars_in_range(
+                &mut char_present_table,
+                &unsanitized_chars[char_index - 1],
+                &unsanitized_chars[char_index + 1],
+                order,
+            )?;
+        } else if let Some(order_index) = order.iter().position(|chr| chr == unsanitized_char) {
+            char_present_table[order_index] = true;
         }
     }
 
@@ -273,7 +831,9 @@ fn sanitize(
 /// 만약 한글 음소가 아니거나 잘못된 위치라면 InvalidPhonemeError를 냅니다.
 ///
 /// orders는 한글 음소의 순서인데, Order::Default.compile()의 결과만 받습니다.
-fn convert_phonemes_to_syllable(
+///
+/// 음소는 패턴에서 바로 오지 않기에 오류의 위치는 빈 범위(`0..0`)입니다.
+pub(crate) fn convert_phonemes_to_syllable(
     chosung: char,
     jungsung: char,
     jongsung: Option<char>,
@@ -285,12 +845,16 @@ fn convert_phonemes_to_syllable(
         return Err(KoreanRegexError::InvalidPhonemeError(
             format!("{chosung} is not valid phoneme."),
             chosung,
+            Some(SlotKind::Chosung),
+            0..0,
         ));
     };
     let Some(jungsung_position) = all_jungsungs.iter().position(|chr| chr == &jungsung) else {
         return Err(KoreanRegexError::InvalidPhonemeError(
             format!("{jungsung} is not valid phoneme."),
             jungsung,
+            Some(SlotKind::Jungsung),
+            0..0,
         ));
     };
     let jongsung_position = if let Some(last) = jongsung {
@@ -298,6 +862,8 @@ fn convert_phonemes_to_syllable(
             return Err(KoreanRegexError::InvalidPhonemeError(
                 format!("{last} is not valid phoneme."),
                 last,
+                Some(SlotKind::Jongsung),
+                0..0,
             ));
         };
         jongsung_position
@@ -311,39 +877,103 @@ fn convert_phonemes_to_syllable(
     .expect("This charactor conversion should succeed. Please create issue if this panic present."))
 }
 
-/// `ㄱㄴㄷㄹ`와 같이 연속된 문자들을 `ㄱ-ㄹ`와 같이 `-`를 이용해 압축합니다.
-fn replace_with_hyphen(string: String) -> String {
-    fn collect_hyphen(hyphen_replaced_chars: &mut Vec<char>, continuous_chars: &mut Vec<char>) {
-        if continuous_chars.len() <= 2 {
-            hyphen_replaced_chars.append(continuous_chars);
-        } else {
-            hyphen_replaced_chars.push(continuous_chars[0]);
-            hyphen_replaced_chars.push('-');
-            hyphen_replaced_chars.push(continuous_chars[continuous_chars.len() - 1]);
-            continuous_chars.clear();
+/// 음소들을 `Order::Default`에서의 위치로 바꾸고 오름차순으로 정렬합니다.
+///
+/// 음소는 패턴에서 바로 오지 않기에 오류의 위치는 빈 범위(`0..0`)입니다.
+fn default_positions(phonemes: &[char], all_phonemes: &[char], kind: SlotKind) -> Result<Vec<u32>, Vec<KoreanRegexError>> {
+    let mut positions = phonemes
+        .iter()
+        .map(|&phoneme| {
+            all_phonemes
+                .iter()
+                .position(|&chr| chr == phoneme)
+                .map(|position| position as u32)
+                .ok_or_else(|| vec![KoreanRegexError::InvalidPhonemeError(
+                    format!("{phoneme} is not valid phoneme."),
+                    phoneme,
+                    Some(kind),
+                    0..0,
+                )])
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    positions.sort_unstable();
+    positions.dedup();
+    Ok(positions)
+}
+
+/// 정렬된 위치들을 연속된 구간(양 끝 포함)으로 묶습니다.
+fn runs(positions: &[u32]) -> Vec<(u32, u32)> {
+    let mut runs: Vec<(u32, u32)> = Vec::new();
+    for &position in positions {
+        match runs.last_mut() {
+            Some((_, end)) if *end + 1 == position => *end = position,
+            _ => runs.push((position, position)),
         }
     }
+    runs
+}
 
-    let mut hyphen_replaced_chars: Vec<char> = Vec::new();
-    let mut continuous_chars: Vec<char> = Vec::new();
-    for chr in string.chars() {
-        if continuous_chars.is_empty() || *continuous_chars.last().unwrap() as u32 + 1 == chr as u32
-        {
-            continuous_chars.push(chr);
-            continue;
+/// 바깥 음소의 위치마다 안쪽 구간들을 `stride`만큼 떨어뜨려 배치하고, 맞닿은 구간은 합칩니다.
+///
+/// 예를 들어 중성이 모두 선택되고 종성도 모두 선택되었다면 중성마다 생기는 28칸짜리 구간들이
+/// 서로 맞닿게 되어 하나의 구간이 됩니다.
+fn spread(outer: &[u32], inner: &[(u32, u32)], stride: u32) -> Vec<(u32, u32)> {
+    let mut result: Vec<(u32, u32)> = Vec::new();
+    for &position in outer {
+        for &(start, end) in inner {
+            let (start, end) = (position * stride + start, position * stride + end);
+            match result.last_mut() {
+                Some((_, last_end)) if *last_end + 1 == start => *last_end = end,
+                _ => result.push((start, end)),
+            }
         }
-        collect_hyphen(&mut hyphen_replaced_chars, &mut continuous_chars);
-        continuous_chars.push(chr);
     }
-    collect_hyphen(&mut hyphen_replaced_chars, &mut continuous_chars);
+    result
+}
+
+/// 초성, 중성, 종성의 `Order::Default` 위치들로부터 조합되는 음절들의 코드 포인트 구간을 계산합니다.
+///
+/// 한글 음절은 `0xAC00 + 588 * 초성 + 28 * 중성 + 종성`의 위치에 있으므로
+/// 음절을 하나하나 만들지 않고도 최소한의 구간들을 오름차순으로 얻을 수 있습니다.
+/// 세 위치 목록은 모두 오름차순으로 정렬되어 있어야 합니다.
+fn syllable_ranges(chosungs: &[u32], jungsungs: &[u32], jongsungs: &[u32]) -> Vec<(u32, u32)> {
+    let jungsung_ranges = spread(jungsungs, &runs(jongsungs), 28);
+    spread(chosungs, &jungsung_ranges, 588)
+        .into_iter()
+        .map(|(start, end)| (0xAC00 + start, 0xAC00 + end))
+        .collect()
+}
 
-    hyphen_replaced_chars.iter().collect()
+/// 음절 구간들을 문자 클래스 안에 들어갈 문자열로 바꿉니다.
+///
+/// 세 글자 이상 이어지는 구간은 `가-갛`과 같이 `-`를 이용해 나타내고,
+/// 그보다 짧은 구간은 글자를 그대로 나열합니다.
+fn render_ranges(ranges: &[(u32, u32)]) -> String {
+    let mut result = String::new();
+    for &(start, end) in ranges {
+        let (first, last) = (char::from_u32(start).unwrap(), char::from_u32(end).unwrap());
+        match end - start {
+            0 => result.push(first),
+            1 => result.extend([first, last]),
+            _ => result.extend([first, '-', last]),
+        }
+    }
+    result
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    /// 각 문자가 한 바이트씩 차지하는 것처럼 위치를 붙입니다.
+    fn spanned(chars: Vec<char>) -> Vec<(char, Range<usize>)> {
+        chars.into_iter().enumerate().map(|(index, chr)| (chr, index..index + 1)).collect()
+    }
+
+    fn chars(spanned: Vec<(char, Range<usize>)>) -> Vec<char> {
+        spanned.into_iter().map(|(chr, _)| chr).collect()
+    }
+
     #[test]
     fn test_unparenthesize() {
         assert_eq!(
@@ -351,36 +981,49 @@ mod test {
                 'ㄼ', 'ㄱ', 'ㄷ', 'ㅊ', 'ㅁ', 'ㅌ', 'ㅈ', 'ㅁ', 'ㄷ', 'ㅘ', 'ㅢ', 'ㅓ', 'ㅑ', 'ㅢ',
                 'ㅓ', 'ㅕ', 'ㅢ'
             ],
-            unparenthesize("(ㄹㅂ)ㄱㄷㅊㅁㅌㅈㅁㄷ(ㅗㅏ)(ㅡㅣ)ㅓㅑㅢㅓㅕ(ㅡㅣ)")
-                .unwrap()
+            chars(unparenthesize("(ㄹㅂ)ㄱㄷㅊㅁㅌㅈㅁㄷ(ㅗㅏ)(ㅡㅣ)ㅓㅑㅢㅓㅕ(ㅡㅣ)").unwrap())
         );
         assert_eq!(
             Vec::<char>::new(),
-            unparenthesize("").unwrap()
+            chars(unparenthesize("").unwrap())
+        );
+        assert_eq!(
+            vec!['ㄿ', 'ㅆ', 'ㅙ'],
+            chars(unparenthesize("(ㄹㅍ)(ㅅㅅ)(ㅗㅐ)").unwrap())
         );
+        match unparenthesize("(ㄹ)").unwrap_err() {
+            KoreanRegexError::UnparenthesizingFailedError(..) => (),
+            _ => panic!("Shoud raise UnparenthesizingFailedError"),
+        };
 
         match unparenthesize("(ㄹㅂ)ㄱㄷ(ㅊㅁㅌㅈㅁㄷ(ㅗㅏ)(ㅡㅣ)ㅓㅑㅢㅓㅕ(ㅡㅣ)")
             .unwrap_err()
         {
-            KoreanRegexError::UnparenthesizingFailedError(_) => (),
+            KoreanRegexError::UnparenthesizingFailedError(..) => (),
             _ => panic!("Shoud raise UnparenthesizingFailedError"),
         };
         match unparenthesize("(ㄹㅂ)ㄱㄷ(ㅊㅁㅌㅈㅁㄷ(ㅗㅏ)(ㅡㅣ)ㅓㅑㅢㅓㅕ(ㅡ)ㅣ)")
             .unwrap_err()
         {
-            KoreanRegexError::UnparenthesizingFailedError(_) => (),
+            KoreanRegexError::UnparenthesizingFailedError(..) => (),
             _ => panic!("Shoud raise UnparenthesizingFailedError"),
         };
         match unparenthesize("(ㄹㅂ)ㄱㄷㅊㅁㅌㅈㅁㄷ(ㅗㅏ)(ㅡㅣ)ㅓㅑ)ㅢㅓㅕ(ㅡㅣ)")
             .unwrap_err()
         {
-            KoreanRegexError::UnparenthesizingFailedError(_) => (),
+            KoreanRegexError::UnparenthesizingFailedError(..) => (),
+            _ => panic!("Shoud raise UnparenthesizingFailedError"),
+        };
+        match unparenthesize("(ㄹㅂ)ㄱㄷ(ㅗㅏ")
+            .unwrap_err()
+        {
+            KoreanRegexError::UnparenthesizingFailedError(..) => (),
             _ => panic!("Shoud raise UnparenthesizingFailedError"),
         };
         match unparenthesize("(ㄹㅂ)ㄱㄷㅊㅁㅌㅈㅁㄷ(ㅗㅏ)(ㅡㅣ)ㅓㅑㅢㅓㅕ(ㅡㅣ))")
             .unwrap_err()
         {
-            KoreanRegexError::UnparenthesizingFailedError(_) => (),
+            KoreanRegexError::UnparenthesizingFailedError(..) => (),
             _ => panic!("Shoud raise UnparenthesizingFailedError"),
         };
     }
@@ -391,40 +1034,40 @@ mod test {
 
         assert_eq!(
             "ㄴㅃㅎ".chars().collect::<Vec<char>>(),
-            sanitize(vec!['ㅃ', 'ㄴ', 'ㅎ'], &order, false).unwrap()
+            sanitize(spanned(vec!['ㅃ', 'ㄴ', 'ㅎ']), &order, false).unwrap()
         );
         assert_eq!(
             "ㄴㅃㅎ".chars().collect::<Vec<char>>(),
-            sanitize(vec!['ㅃ', 'ㄴ', 'ㅎ', 'ㅎ', 'ㅃ'], &order, false).unwrap()
+            sanitize(spanned(vec!['ㅃ', 'ㄴ', 'ㅎ', 'ㅎ', 'ㅃ']), &order, false).unwrap()
         );
         assert_eq!(
             "ㄱㄲㄷㄸㄹㅁㅂㅅㅆㅇㅈㅉㅊㅋㅌㅍ".chars().collect::<Vec<char>>(),
-            sanitize(vec!['ㅃ', 'ㄴ', 'ㅎ'], &order, true).unwrap()
+            sanitize(spanned(vec!['ㅃ', 'ㄴ', 'ㅎ']), &order, true).unwrap()
         );
         assert_eq!(
             "ㄴㄷㄸㄹㅃㅎ".chars().collect::<Vec<char>>(),
-            sanitize(vec!['ㅃ', 'ㄴ', '-', 'ㄹ', 'ㅎ'], &order, false).unwrap()
+            sanitize(spanned(vec!['ㅃ', 'ㄴ', '-', 'ㄹ', 'ㅎ']), &order, false).unwrap()
         );
         assert_eq!(
             "ㄱㄲㄴㄷㄸㄹㅁㅂㅃㅅㅆㅇㅈㅉㅊㅋㅌㅍㅎ".chars().collect::<Vec<char>>(),
-            sanitize(vec!['ㅃ', 'ㄱ', '-', 'ㅎ'], &order, false).unwrap()
+            sanitize(spanned(vec!['ㅃ', 'ㄱ', '-', 'ㅎ']), &order, false).unwrap()
         );
         assert_eq!(
             "ㄱㄲㅁㅂㅅㅆㅇㅈㅉㅊㅋㅌㅍ".chars().collect::<Vec<char>>(),
-            sanitize(vec!['ㅃ', 'ㄴ', '-', 'ㄹ', 'ㅎ'], &order, true).unwrap()
+            sanitize(spanned(vec!['ㅃ', 'ㄴ', '-', 'ㄹ', 'ㅎ']), &order, true).unwrap()
         );
 
-        match sanitize(vec!['ㅃ', 'ㄹ', '-', 'ㄴ', 'ㅎ'], &order, true).unwrap_err() {
-            KoreanRegexError::InvalidHyphenError(_) => (),
+        match sanitize(spanned(vec!['ㅃ', 'ㄹ', '-', 'ㄴ', 'ㅎ']), &order, true).unwrap_err() {
+            KoreanRegexError::InvalidHyphenError(..) => (),
             _ => panic!("Shoud raise InvalidHyphenError"),
         };
 
         // 확인할 수 없는 문자열 있을 때 검사
         assert_eq!(
             "ㄴㄷㄸㄹ".chars().collect::<Vec<char>>(),
-            sanitize(vec!['ㄴ', '-', 'ㄹ', 'h', ' '], &order, false).unwrap()
+            sanitize(spanned(vec!['ㄴ', '-', 'ㄹ', 'h', ' ']), &order, false).unwrap()
         );
-        match sanitize(vec!['ㄴ', '-', 'h'], &order, false).unwrap_err() {
+        match sanitize(spanned(vec!['ㄴ', '-', 'h']), &order, false).unwrap_err() {
             KoreanRegexError::InvalidPhonemeError(..) => (),
             _ => panic!("Shoud raise InvalidPhonemeError"),
         };
@@ -432,19 +1075,19 @@ mod test {
         // 빈 문자열 검사
         assert_eq!(
             "".chars().collect::<Vec<char>>(),
-            sanitize(Vec::<char>::new(), &order, false).unwrap()
+            sanitize(spanned(Vec::<char>::new()), &order, false).unwrap()
         );
         assert_eq!(
             "ㄱㄲㄴㄷㄸㄹㅁㅂㅃㅅㅆㅇㅈㅉㅊㅋㅌㅍㅎ".chars().collect::<Vec<char>>(),
-            sanitize(Vec::<char>::new(), &order, true).unwrap()
+            sanitize(spanned(Vec::<char>::new()), &order, true).unwrap()
         );
 
-        match sanitize(vec!['-', 'ㅃ', 'ㄴ', 'ㄹ', 'ㅎ'], &order, true).unwrap_err() {
-            KoreanRegexError::InvalidHyphenError(_) => (),
+        match sanitize(spanned(vec!['-', 'ㅃ', 'ㄴ', 'ㄹ', 'ㅎ']), &order, true).unwrap_err() {
+            KoreanRegexError::InvalidHyphenError(..) => (),
             _ => panic!("Shoud raise InvalidHyphenError"),
         };
-        match sanitize(vec!['ㅃ', 'ㄴ', 'ㄹ', 'ㅎ', '-'], &order, true).unwrap_err() {
-            KoreanRegexError::InvalidHyphenError(_) => (),
+        match sanitize(spanned(vec!['ㅃ', 'ㄴ', 'ㄹ', 'ㅎ', '-']), &order, true).unwrap_err() {
+            KoreanRegexError::InvalidHyphenError(..) => (),
             _ => panic!("Shoud raise InvalidHyphenError"),
         };
     }
@@ -463,31 +1106,31 @@ mod test {
         match convert_phonemes_to_syllable('ㄷ', 'ㅝ', Some('d'), Order::Default.order())
             .unwrap_err()
         {
-            KoreanRegexError::InvalidPhonemeError(_, syllable) => assert_eq!('d', syllable),
+            KoreanRegexError::InvalidPhonemeError(_, syllable, ..) => assert_eq!('d', syllable),
             _ => panic!("Shoud raise InvalidPhonemeError"),
         };
         match convert_phonemes_to_syllable('ㄷ', 'f', Some('ㅇ'), Order::Default.order())
             .unwrap_err()
         {
-            KoreanRegexError::InvalidPhonemeError(_, syllable) => assert_eq!('f', syllable),
+            KoreanRegexError::InvalidPhonemeError(_, syllable, ..) => assert_eq!('f', syllable),
             _ => panic!("Shoud raise InvalidPhonemeError"),
         };
         match convert_phonemes_to_syllable('ㄷ', 'ㄷ', Some('ㅇ'), Order::Default.order())
             .unwrap_err()
         {
-            KoreanRegexError::InvalidPhonemeError(_, syllable) => assert_eq!('ㄷ', syllable),
+            KoreanRegexError::InvalidPhonemeError(_, syllable, ..) => assert_eq!('ㄷ', syllable),
             _ => panic!("Shoud raise InvalidPhonemeError"),
         };
         match convert_phonemes_to_syllable('ㅏ', 'ㅏ', Some('ㅇ'), Order::Default.order())
             .unwrap_err()
         {
-            KoreanRegexError::InvalidPhonemeError(_, syllable) => assert_eq!('ㅏ', syllable),
+            KoreanRegexError::InvalidPhonemeError(_, syllable, ..) => assert_eq!('ㅏ', syllable),
             _ => panic!("Shoud raise InvalidPhonemeError"),
         };
         match convert_phonemes_to_syllable('e', 'ㅏ', Some('ㅇ'), Order::Default.order())
             .unwrap_err()
         {
-            KoreanRegexError::InvalidPhonemeError(_, syllable) => assert_eq!('e', syllable),
+            KoreanRegexError::InvalidPhonemeError(_, syllable, ..) => assert_eq!('e', syllable),
             _ => panic!("Shoud raise InvalidPhonemeError"),
         };
     }
@@ -542,15 +1185,15 @@ mod test {
                    substitute("ㄱㄷㅊㅇ", "ㅏㅣ(ㅡㅣ)", "ㄱ(ㄹㅂ)ㅈ0", order, false).unwrap());
 
         match substitute("0", "0", "0", order, false).unwrap_err() {
-            KoreanRegexError::InvalidZeroPatternError(_) => (),
+            KoreanRegexError::InvalidZeroPatternError(..) => (),
             _ => panic!("Shoud raise InvalidZeroPatternError"),
         }
         match substitute("0", "ㅏ", "ㅁ", order, false).unwrap_err() {
-            KoreanRegexError::InvalidZeroPatternError(_) => (),
+            KoreanRegexError::InvalidZeroPatternError(..) => (),
             _ => panic!("Shoud   raise InvalidZeroPatternError"),
         }
         match substitute("ㅎ", "0", "ㅁ", order, false).unwrap_err() {
-            KoreanRegexError::InvalidZeroPatternError(_) => (),
+            KoreanRegexError::InvalidZeroPatternError(..) => (),
             _ => panic!("Shoud raise InvalidZeroPatternError"),
         }
     }
@@ -592,27 +1235,122 @@ mod test {
             substitute("0", "0", "ㅇ(ㄹㅂ)ㅅㅆ", order, false).unwrap()
         );
 
+        // 하이픈을 사용하면 순서와 관계없이 코드 포인트 순으로 정렬됨
+        // (0.3.0 이전에는 "가각간갇갈감갑갓강-갛갂갃갅갆갉-갏값갔")
         assert_eq!(
-            "가각간갇갈감갑갓강-갛갂갃갅갆갉-갏값갔",
+            "가-갛",
             &substitute("ㄱ", "ㅏ", "", order, true).unwrap()
         );
 
         match substitute("0", "0", "0", order, false).unwrap_err() {
-            KoreanRegexError::InvalidZeroPatternError(_) => (),
+            KoreanRegexError::InvalidZeroPatternError(..) => (),
             _ => panic!("Shoud raise InvalidZeroPatternError"),
         }
         match substitute("0", "ㅏ", "ㅁ", order, false).unwrap_err() {
-            KoreanRegexError::InvalidZeroPatternError(_) => (),
+            KoreanRegexError::InvalidZeroPatternError(..) => (),
             _ => panic!("Shoud raise InvalidZeroPatternError"),
         }
         match substitute("ㅎ", "0", "ㅁ", order, false).unwrap_err() {
-            KoreanRegexError::InvalidZeroPatternError(_) => (),
+            KoreanRegexError::InvalidZeroPatternError(..) => (),
             _ => panic!("Shoud raise InvalidZeroPatternError"),
         }
     }
 
     #[test]
     fn test_replace_with_hyphen() {
-        dbg!(replace_with_hyphen("강당항".to_string()));
+        // 하이픈 대체는 순서와 관계없이 코드 포인트가 이어지는 음절을 묶음
+        for order in [Order::Default, Order::RegularFirst] {
+            assert_eq!("강당항", substitute("ㄱㄷㅎ", "ㅏ", "ㅇ", order, true).unwrap());
+            assert_eq!("가-갛", substitute("ㄱ", "ㅏ", "", order, true).unwrap());
+            assert_eq!("가-깋", substitute("ㄱ", "", "", order, true).unwrap());
+        }
+        // 하이픈을 사용하지 않으면 순서를 따름
+        assert_eq!("ㄱㄴㄲ", substitute("ㄱㄴㄲ", "0", "0", Order::RegularFirst, false).unwrap());
+    }
+
+    #[test]
+    fn test_render_ranges() {
+        assert_eq!("강당항", render_ranges(&[(0xAC15, 0xAC15), (0xB2F9, 0xB2F9), (0xD56D, 0xD56D)]));
+        assert_eq!("가각", render_ranges(&[(0xAC00, 0xAC01)]));
+        assert_eq!("가-갛", render_ranges(&[(0xAC00, 0xAC1B)]));
+    }
+
+    #[test]
+    fn test_syllable_ranges() {
+        let all_chosungs: Vec<u32> = (0..19).collect();
+        let all_jungsungs: Vec<u32> = (0..21).collect();
+        let all_jongsungs: Vec<u32> = (0..28).collect();
+        assert_eq!(vec![(0xAC00, 0xD7A3)], syllable_ranges(&all_chosungs, &all_jungsungs, &all_jongsungs));
+
+        assert_eq!(
+            vec![(0xAC00, 0xAC00), (0xAC1C, 0xAC1C), (0xB098, 0xB098), (0xB0B4, 0xB0B4)],
+            syllable_ranges(&[0, 2], &[0, 1], &[0]),
+        );
+        assert_eq!(
+            vec![(0xAC01, 0xAC02), (0xAC1D, 0xAC1E)],
+            syllable_ranges(&[0], &[0, 1], &[1, 2]),
+        );
+    }
+
+    #[test]
+    fn test_hyphen_matches_enumeration() {
+        let order = Order::Default;
+        for (chosungs, jungsungs, jongsungs) in [
+            ("", "", ""),
+            ("ㄱ-ㄹ", "ㅏ", "ㄴ"),
+            ("^ㄱ", "ㅗ-ㅢ", ""),
+            ("ㅎ", "", "0"),
+            ("ㄱㄷ", "ㅐㅔ", "^ㄱㄴ"),
+        ] {
+            let enumerated = substitute(chosungs, jungsungs, jongsungs, order, false).unwrap();
+            let mut enumerated: Vec<char> = enumerated.chars().collect();
+            enumerated.sort_unstable();
+            let expected: String = enumerated.into_iter().collect();
+            let hyphenated = substitute(chosungs, jungsungs, jongsungs, order, true).unwrap();
+            let expanded = regex::Regex::new(&format!("^[{hyphenated}]$")).unwrap();
+            assert_eq!(
+                expected.chars().count(),
+                (0xAC00..=0xD7A3)
+                    .filter_map(char::from_u32)
+                    .filter(|chr| expanded.is_match(&chr.to_string()))
+                    .count(),
+            );
+            assert!(expected.chars().all(|chr| expanded.is_match(&chr.to_string())));
+        }
+    }
+
+    #[test]
+    fn test_named_sets() {
+        use crate::{compilestr, ErrorKind};
+
+        let order = Order::Default;
+        // 자모나 알려진 집합이 없는 클래스는 원래의 정규표현식 문자 클래스로 남음
+        assert_eq!("[@abc:]", compilestr("[@abc:]", order).unwrap());
+        assert_eq!(compilestr("[ㄲㄸㅃㅆㅉ::]", order).unwrap(), compilestr("[@경음::]", order).unwrap());
+        assert_eq!(compilestr("[::ㄳㄵㄶㄺㄻㄼㄽㄾㄿㅀㅄ]", order).unwrap(), compilestr("[::@겹받침]", order).unwrap());
+        assert_eq!("까따빠싸짜", substitute("@경음", "ㅏ", "0", order, false).unwrap());
+        assert_eq!("갂갔", substitute("ㄱ", "ㅏ", "@경음", order, false).unwrap());
+        assert_eq!("가까나차카타파", substitute("ㄱ-ㄴ@격음", "ㅏ", "0", order, false).unwrap());
+        assert_eq!(
+            substitute("^ㄲㄸㅃㅆㅉ", "ㅗ", "0", order, false).unwrap(),
+            substitute("^@경음", "ㅗ", "0", order, false).unwrap()
+        );
+        assert_eq!(
+            substitute("ㄱ", "ㅗ", "ㄳㄵㄶㄺㄻㄼㄽㄾㄿㅀㅄ", order, false).unwrap(),
+            substitute("ㄱ", "ㅗ", "@겹받침", order, false).unwrap()
+        );
+
+        let error = substitute("@없는집합", "ㅏ", "", order, false).unwrap_err();
+        assert_eq!(ErrorKind::InvalidSet, error.kind());
+        assert_eq!(Some(SlotKind::Chosung), error.slot());
+        assert_eq!("@없는집합", &"[@없는집합:ㅏ:]"[error.span()]);
+
+        let error = substitute("ㄱ", "@경음", "", order, false).unwrap_err();
+        assert_eq!(ErrorKind::InvalidSet, error.kind());
+        assert_eq!(Some(SlotKind::Jungsung), error.slot());
+
+        let error = substitute("ㄱ-@경음", "ㅏ", "", order, false).unwrap_err();
+        assert_eq!(ErrorKind::InvalidHyphen, error.kind());
+        assert_eq!("@경음", &"[ㄱ-@경음:ㅏ:]"[error.span()]);
     }
 }